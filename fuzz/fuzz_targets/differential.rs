@@ -0,0 +1,212 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use overflow_proof::{Checked, Unchecked, WithoutDeref};
+use std::convert::TryFrom;
+
+/// Exact reference value, in sign and magnitude form
+///
+/// Any magnitude that doesn't fit in `u128` is out of range for all
+/// primitive types, so this is as good as arbitrary precision here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Exact {
+    neg: bool,
+    mag: u128,
+}
+
+impl Exact {
+    fn new(neg: bool, mag: u128) -> Self {
+        Self {
+            neg: neg && mag != 0,
+            mag,
+        }
+    }
+
+    fn add(self, rhs: Self) -> Option<Self> {
+        if self.neg == rhs.neg {
+            Some(Self::new(self.neg, self.mag.checked_add(rhs.mag)?))
+        } else if rhs.mag <= self.mag {
+            Some(Self::new(self.neg, self.mag - rhs.mag))
+        } else {
+            Some(Self::new(rhs.neg, rhs.mag - self.mag))
+        }
+    }
+
+    fn sub(self, rhs: Self) -> Option<Self> {
+        self.add(Self::new(!rhs.neg, rhs.mag))
+    }
+
+    fn mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(self.neg != rhs.neg, self.mag.checked_mul(rhs.mag)?))
+    }
+
+    fn div(self, rhs: Self) -> Option<Self> {
+        if rhs.mag == 0 {
+            return None;
+        }
+        Some(Self::new(self.neg != rhs.neg, self.mag / rhs.mag))
+    }
+
+    fn rem(self, rhs: Self) -> Option<Self> {
+        if rhs.mag == 0 {
+            return None;
+        }
+        Some(Self::new(self.neg, self.mag % rhs.mag))
+    }
+}
+
+trait Reference: Sized + Copy {
+    fn to_exact(self) -> Exact;
+    fn from_exact(v: Exact) -> Option<Self>;
+}
+
+macro_rules! impl_reference {
+    ($($t:ty),*) => {
+        $(
+            impl Reference for $t {
+                #[allow(unused_comparisons)]
+                fn to_exact(self) -> Exact {
+                    if self < 0 {
+                        Exact::new(true, (self as i128).unsigned_abs())
+                    } else {
+                        Exact::new(false, self as u128)
+                    }
+                }
+
+                fn from_exact(v: Exact) -> Option<Self> {
+                    if v.neg {
+                        if v.mag > i128::MAX as u128 + 1 {
+                            return None;
+                        }
+                        <$t>::try_from((v.mag as i128).wrapping_neg()).ok()
+                    } else {
+                        <$t>::try_from(v.mag).ok()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_reference!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+#[derive(Arbitrary, Debug)]
+enum Width {
+    Usize,
+    Isize,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+}
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    DivExact,
+}
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Operand {
+    /// Raw bits of the inner type
+    Same(u128),
+    /// Value of another type, converted with `TryInto`
+    Int(i128),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    width: Width,
+    start: u128,
+    ops: Vec<(Op, Operand)>,
+}
+
+fn reference_step<T: Reference>(acc: T, op: Op, rhs: T) -> Option<T> {
+    let (l, r) = (acc.to_exact(), rhs.to_exact());
+    match op {
+        Op::Add => T::from_exact(l.add(r)?),
+        Op::Sub => T::from_exact(l.sub(r)?),
+        Op::Mul => T::from_exact(l.mul(r)?),
+        Op::Div => T::from_exact(l.div(r)?),
+        Op::Rem => {
+            // `MIN % -1` overflows in the primitive types, as `MIN / -1` does
+            T::from_exact(l.div(r)?)?;
+            T::from_exact(l.rem(r)?)
+        }
+        Op::DivExact => {
+            if l.rem(r)?.mag != 0 {
+                return None;
+            }
+            T::from_exact(l.div(r)?)
+        }
+    }
+}
+
+macro_rules! run {
+    ($t:ty, $input:expr) => {{
+        let start = $input.start as $t;
+        let mut expected: Option<$t> = Some(start);
+        let mut acc: Unchecked<$t, WithoutDeref> = Checked::new_without_deref(start) * 1;
+
+        for &(op, operand) in &$input.ops {
+            let rhs = match operand {
+                Operand::Same(raw) => Some(raw as $t),
+                Operand::Int(i) => <$t>::try_from(i).ok(),
+            };
+            expected = match (expected, rhs) {
+                (Some(e), Some(rhs)) => reference_step(e, op, rhs),
+                _ => None,
+            };
+            acc = match (operand, op) {
+                (Operand::Same(raw), Op::Add) => acc + (raw as $t),
+                (Operand::Same(raw), Op::Sub) => acc - (raw as $t),
+                (Operand::Same(raw), Op::Mul) => acc * (raw as $t),
+                (Operand::Same(raw), Op::Div) => acc / (raw as $t),
+                (Operand::Same(raw), Op::Rem) => acc % (raw as $t),
+                (Operand::Same(raw), Op::DivExact) => acc.div_exact(raw as $t),
+                (Operand::Int(i), Op::Add) => acc.try_add(i),
+                (Operand::Int(i), Op::Sub) => acc.try_sub(i),
+                (Operand::Int(i), Op::Mul) => acc.try_mul(i),
+                (Operand::Int(i), Op::Div) => acc.try_div(i),
+                (Operand::Int(i), Op::Rem) => acc.try_rem(i),
+                (Operand::Int(i), Op::DivExact) => acc.div_exact(i),
+            };
+
+            assert_eq!(
+                acc.check().map(Checked::into_inner),
+                expected,
+                "{:?} {:?}",
+                op,
+                operand
+            );
+        }
+    }};
+}
+
+fuzz_target!(|input: Input| {
+    match input.width {
+        Width::Usize => run!(usize, input),
+        Width::Isize => run!(isize, input),
+        Width::U8 => run!(u8, input),
+        Width::I8 => run!(i8, input),
+        Width::U16 => run!(u16, input),
+        Width::I16 => run!(i16, input),
+        Width::U32 => run!(u32, input),
+        Width::I32 => run!(i32, input),
+        Width::U64 => run!(u64, input),
+        Width::I64 => run!(i64, input),
+        Width::U128 => run!(u128, input),
+        Width::I128 => run!(i128, input),
+    }
+});
@@ -0,0 +1,28 @@
+use super::{Checked, WithoutDeref};
+
+macro_rules! aliases {
+    ($($t:ty => $with:ident, $without:ident);* $(;)?) => {
+        $(
+            #[doc = concat!("[`Checked`] `", stringify!($t), "`")]
+            pub type $with = Checked<$t>;
+
+            #[doc = concat!("[`Checked`] `", stringify!($t), "` that must be explicitly converted to the inner type")]
+            pub type $without = Checked<$t, WithoutDeref>;
+        )*
+    };
+}
+
+aliases!(
+    usize => CheckedUsize, CheckedUsizeWithoutDeref;
+    isize => CheckedIsize, CheckedIsizeWithoutDeref;
+    u8 => CheckedU8, CheckedU8WithoutDeref;
+    i8 => CheckedI8, CheckedI8WithoutDeref;
+    u16 => CheckedU16, CheckedU16WithoutDeref;
+    i16 => CheckedI16, CheckedI16WithoutDeref;
+    u32 => CheckedU32, CheckedU32WithoutDeref;
+    i32 => CheckedI32, CheckedI32WithoutDeref;
+    u64 => CheckedU64, CheckedU64WithoutDeref;
+    i64 => CheckedI64, CheckedI64WithoutDeref;
+    u128 => CheckedU128, CheckedU128WithoutDeref;
+    i128 => CheckedI128, CheckedI128WithoutDeref;
+);
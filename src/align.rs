@@ -0,0 +1,70 @@
+use super::{Checked, Overflow, Unchecked};
+
+macro_rules! impl_align {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Round up to a multiple of `align`
+                ///
+                /// Poisoned if `align` is not a power of two, or on overflow.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn align_up(self, align: $t) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: if align.is_power_of_two() {
+                            let mask = align - 1;
+                            self.v.checked_add(mask).map(|v| v & !mask)
+                        } else {
+                            None
+                        },
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+
+                /// Round down to a multiple of `align`
+                ///
+                /// Poisoned if `align` is not a power of two.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn align_down(self, align: $t) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: if align.is_power_of_two() {
+                            Some(self.v & !(align - 1))
+                        } else {
+                            None
+                        },
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+
+                /// Is the value a multiple of `align`?
+                ///
+                /// Always `false` if `align` is not a power of two.
+                pub fn is_aligned(&self, align: $t) -> bool {
+                    align.is_power_of_two() && self.v & (align - 1) == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_align!(usize, u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align() {
+        let addr = Checked::new(4097usize);
+        assert_eq!(*addr.align_up(4096).check().unwrap(), 8192);
+        assert_eq!(*addr.align_down(4096).check().unwrap(), 4096);
+        assert!(addr.align_up(3).check().is_none());
+        assert!(addr.align_down(0).check().is_none());
+        assert!(Checked::new(usize::MAX - 1).align_up(4).check().is_none());
+
+        assert!(Checked::new(8192usize).is_aligned(4096));
+        assert!(!addr.is_aligned(4096));
+        assert!(!addr.is_aligned(0));
+    }
+}
@@ -0,0 +1,67 @@
+use super::{Checked, Overflow, OverflowBound, Unchecked};
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+use core::marker::PhantomData;
+
+impl<'a, T, D> Arbitrary<'a> for Checked<T, D>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Checked::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+/// Roughly one in eight values is an overflow, past a random bound or an unknown one
+impl<'a, T, D> Arbitrary<'a> for Unchecked<T, D>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (v, bound) = if u.ratio(1u8, 8)? {
+            let bound = *u.choose(&[None, Some(OverflowBound::Min), Some(OverflowBound::Max)])?;
+            (None, bound)
+        } else {
+            (Some(T::arbitrary(u)?), None)
+        };
+        Ok(Unchecked {
+            v,
+            overflow: Overflow::past(bound),
+            _deref: PhantomData,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(
+            <u8 as Arbitrary>::size_hint(depth),
+            size_hint::or((1, Some(1)), T::size_hint(depth)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+        let v = Checked::<u16>::arbitrary(&mut u).unwrap();
+        assert_eq!(*v, u16::from_le_bytes([0, 1]));
+
+        let mut overflowed = 0;
+        let mut valid = 0;
+        while !u.is_empty() {
+            match Unchecked::<u8>::arbitrary(&mut u).unwrap().check() {
+                Some(_) => valid += 1,
+                None => overflowed += 1,
+            }
+        }
+        assert!(overflowed > 0);
+        assert!(valid > overflowed);
+    }
+}
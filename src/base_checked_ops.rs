@@ -1,93 +1,898 @@
 use super::Checked;
+#[cfg(feature = "rich-errors")]
+use super::{FailedOperation, OperationKind};
+use core::convert::{TryFrom, TryInto};
+
+/// Bound of the inner type that an overflowing result went past
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowBound {
+    /// The result was smaller than the minimum value
+    Min,
+    /// The result was larger than the maximum value
+    Max,
+}
+
+/// What is known about an overflow, carried along by further operations on [`Unchecked`]
+///
+/// [`Unchecked`]: crate::Unchecked
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Overflow {
+    pub(crate) bound: Option<OverflowBound>,
+    #[cfg(feature = "rich-errors")]
+    pub(crate) operation: Option<FailedOperation>,
+}
+
+impl Overflow {
+    /// Overflow past `bound` in `operation`, where known
+    #[cfg(feature = "rich-errors")]
+    #[inline(always)]
+    pub fn new(bound: Option<OverflowBound>, operation: Option<FailedOperation>) -> Self {
+        Overflow { bound, operation }
+    }
+
+    /// Bound of the inner type that the result went past, if known
+    pub fn bound(&self) -> Option<OverflowBound> {
+        self.bound
+    }
+
+    /// Operation that overflowed, if recorded
+    #[cfg(feature = "rich-errors")]
+    pub fn operation(&self) -> Option<&FailedOperation> {
+        self.operation.as_ref()
+    }
+
+    /// Overflow past `bound`, with the operation not recorded
+    #[inline(always)]
+    pub(crate) fn past(bound: Option<OverflowBound>) -> Self {
+        Overflow {
+            bound,
+            #[cfg(feature = "rich-errors")]
+            operation: None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn map_bound(mut self, f: fn(Option<OverflowBound>) -> Option<OverflowBound>) -> Self {
+        self.bound = f(self.bound);
+        self
+    }
+}
+
+/// Types with a minimum and maximum value
+pub trait Bounded {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+/// Types with an additive identity
+pub trait Zero {
+    const ZERO: Self;
+}
+
+/// Types with a multiplicative identity
+pub trait One {
+    const ONE: Self;
+}
 
 pub trait CheckedAdd<Rhs = Self> {
     type Output;
 
     fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_add()`, but on overflow also tells which bound was crossed, if known
+    fn checked_add_bounded(self, rhs: Rhs) -> Result<Self::Output, Option<OverflowBound>>
+    where
+        Self: Sized,
+    {
+        self.checked_add(rhs).ok_or(None)
+    }
+
+    /// Like `checked_add_bounded()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_add_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_add_bounded(rhs).map_err(|bound| Overflow::new(bound, None))
+    }
 }
 
 pub trait CheckedSub<Rhs = Self> {
     type Output;
 
     fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_sub()`, but on overflow also tells which bound was crossed, if known
+    fn checked_sub_bounded(self, rhs: Rhs) -> Result<Self::Output, Option<OverflowBound>>
+    where
+        Self: Sized,
+    {
+        self.checked_sub(rhs).ok_or(None)
+    }
+
+    /// Like `checked_sub_bounded()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_sub_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_sub_bounded(rhs).map_err(|bound| Overflow::new(bound, None))
+    }
 }
 
 pub trait CheckedMul<Rhs = Self> {
     type Output;
 
     fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_mul()`, but on overflow also tells which bound was crossed, if known
+    fn checked_mul_bounded(self, rhs: Rhs) -> Result<Self::Output, Option<OverflowBound>>
+    where
+        Self: Sized,
+    {
+        self.checked_mul(rhs).ok_or(None)
+    }
+
+    /// Like `checked_mul_bounded()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_mul_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_mul_bounded(rhs).map_err(|bound| Overflow::new(bound, None))
+    }
 }
 
 pub trait CheckedDiv<Rhs = Self> {
     type Output;
 
     fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_div()`, but on overflow also tells which bound was crossed, if known
+    fn checked_div_bounded(self, rhs: Rhs) -> Result<Self::Output, Option<OverflowBound>>
+    where
+        Self: Sized,
+    {
+        self.checked_div(rhs).ok_or(None)
+    }
+
+    /// Like `checked_div_bounded()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_div_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_div_bounded(rhs).map_err(|bound| Overflow::new(bound, None))
+    }
 }
 
 pub trait CheckedRem<Rhs = Self> {
     type Output;
 
     fn checked_rem(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_rem()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_rem_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_rem(rhs).ok_or(Overflow::new(None, None))
+    }
+}
+
+pub trait CheckedDivEuclid<Rhs = Self> {
+    type Output;
+
+    fn checked_div_euclid(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_div_euclid()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_div_euclid_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_div_euclid(rhs).ok_or(Overflow::new(None, None))
+    }
+}
+
+pub trait CheckedRemEuclid<Rhs = Self> {
+    type Output;
+
+    fn checked_rem_euclid(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Like `checked_rem_euclid()`, but on overflow also describes the operation, if known
+    #[cfg(feature = "rich-errors")]
+    fn checked_rem_euclid_detailed(self, rhs: Rhs) -> Result<Self::Output, Overflow>
+    where
+        Self: Sized,
+    {
+        self.checked_rem_euclid(rhs).ok_or(Overflow::new(None, None))
+    }
+}
+
+pub trait CheckedAbsDiff<Rhs = Self> {
+    type Output;
+
+    fn checked_abs_diff(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub trait CheckedNextMultipleOf<Rhs = Self> {
+    type Output;
+
+    fn checked_next_multiple_of(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub trait CheckedNextPowerOfTwo : Sized {
+    type Output;
+
+    fn checked_next_power_of_two(self) -> Option<Self>;
+}
+
+pub trait CheckedIlog<Base = Self> {
+    type Output;
+
+    fn checked_ilog(self, base: Base) -> Option<Self::Output>;
+}
+
+pub trait CheckedIlog2 {
+    fn checked_ilog2(self) -> Option<u32>;
+}
+
+pub trait CheckedIlog10 {
+    fn checked_ilog10(self) -> Option<u32>;
+}
+
+pub trait CheckedIsqrt : Sized {
+    type Output;
+
+    fn checked_isqrt(self) -> Option<Self>;
+}
+
+pub trait CheckedShl<Rhs = u32> {
+    type Output;
+
+    fn checked_shl(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub trait CheckedShr<Rhs = u32> {
+    type Output;
+
+    fn checked_shr(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub trait CheckedPow<Exp = u32> {
+    type Output;
+
+    fn checked_pow(self, exp: Exp) -> Option<Self::Output>;
+
+    /// Like `checked_pow()`, but on overflow also tells which bound was crossed, if known
+    fn checked_pow_bounded(self, exp: Exp) -> Result<Self::Output, Option<OverflowBound>>
+    where
+        Self: Sized,
+    {
+        self.checked_pow(exp).ok_or(None)
+    }
+}
+
+pub trait CheckedDivExact<Rhs = Self> {
+    type Output;
+
+    fn checked_div_exact(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub trait Narrow<T> {
+    fn checked_narrow(self) -> Option<T>;
+
+    fn saturating_narrow(self) -> T;
+
+    fn wrapping_narrow(self) -> T;
 }
 
 pub trait CheckedAbs : Sized {
     type Output;
 
     fn checked_abs(self) -> Option<Self>;
+
+    /// Like `checked_abs()`, but on overflow also tells which bound was crossed, if known
+    fn checked_abs_bounded(self) -> Result<Self, Option<OverflowBound>> {
+        self.checked_abs().ok_or(None)
+    }
 }
 
 pub trait CheckedNeg : Sized {
     type Output;
 
     fn checked_neg(self) -> Option<Self>;
+
+    /// Like `checked_neg()`, but on overflow also tells which bound was crossed, if known
+    fn checked_neg_bounded(self) -> Result<Self, Option<OverflowBound>> {
+        self.checked_neg().ok_or(None)
+    }
+}
+
+pub trait WideningMul<Rhs = Self> {
+    type Output;
+
+    fn widening_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait Midpoint<Rhs = Self> {
+    type Output;
+
+    fn midpoint(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait CarryingAdd<Rhs = Self> {
+    type Output;
+
+    fn carrying_add(self, rhs: Rhs, carry: bool) -> (Self::Output, bool);
+}
+
+pub trait BorrowingSub<Rhs = Self> {
+    type Output;
+
+    fn borrowing_sub(self, rhs: Rhs, borrow: bool) -> (Self::Output, bool);
+}
+
+pub trait CheckedMulDiv<Rhs = Self> {
+    type Output;
+
+    fn checked_mul_div(self, mul: Rhs, div: Rhs) -> Option<Self::Output>;
+}
+
+pub trait OverflowingAdd<Rhs = Self> {
+    type Output;
+
+    fn overflowing_add(self, rhs: Rhs) -> (Self::Output, bool);
+}
+
+pub trait OverflowingSub<Rhs = Self> {
+    type Output;
+
+    fn overflowing_sub(self, rhs: Rhs) -> (Self::Output, bool);
+}
+
+pub trait OverflowingMul<Rhs = Self> {
+    type Output;
+
+    fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, bool);
 }
 
 
+// Right hand side is only of the same type, so integer literals infer to it.
+//
+// If a saturating counterpart is given, the bound crossed on overflow is
+// reported by the `_bounded` variant. With `rich-errors`, the `_detailed`
+// variant also records the operation of the given kind.
 macro_rules! impl_checked_trait_2_for {
-    ($checked_t:tt, $checked_op:ident, $t:ty) => {
+    ($checked_t:tt, $checked_op:ident, $t:ty $(; $kind:ident => $detailed_op:ident $(, $bounded_op:ident, $saturating_op:ident)?)?) => {
 
         impl $checked_t for $t {
             type Output = $t;
 
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
             fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
                 self.$checked_op(rhs)
             }
+
+            $(
+                $(
+                    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                    fn $bounded_op(self, rhs: Self) -> Result<Self::Output, Option<OverflowBound>> {
+                        <$t as $checked_t>::$checked_op(self, rhs).ok_or_else(|| {
+                            // Division by zero has no bound to speak of
+                            if rhs == 0 {
+                                None
+                            } else if self.$saturating_op(rhs) == <$t>::MAX {
+                                Some(OverflowBound::Max)
+                            } else {
+                                Some(OverflowBound::Min)
+                            }
+                        })
+                    }
+                )?
+
+                #[cfg(feature = "rich-errors")]
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $detailed_op(self, rhs: Self) -> Result<Self::Output, Overflow> {
+                    bounded_result!(<$t as $checked_t>::$checked_op $(, $bounded_op)?)(self, rhs).map_err(|bound| {
+                        Overflow::new(
+                            bound,
+                            Some(FailedOperation::new(stringify!($t), OperationKind::$kind, self, rhs)),
+                        )
+                    })
+                }
+            )?
         }
 
         impl<D> $checked_t<Checked<$t, D>> for $t {
             type Output = $t;
 
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
             fn $checked_op(self, rhs: Checked<$t, D>) -> Option<Self::Output> {
                 self.$checked_op(rhs.v)
             }
+
+            $(
+                $(
+                    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                    fn $bounded_op(self, rhs: Checked<$t, D>) -> Result<Self::Output, Option<OverflowBound>> {
+                        <$t as $checked_t>::$bounded_op(self, rhs.v)
+                    }
+                )?
+
+                #[cfg(feature = "rich-errors")]
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $detailed_op(self, rhs: Checked<$t, D>) -> Result<Self::Output, Overflow> {
+                    <$t as $checked_t>::$detailed_op(self, rhs.v)
+                }
+            )?
         }
     }
 }
 
+// Function returning the result of a checked operation, with the bound crossed
+// on overflow if the operation tracks it
+#[cfg(feature = "rich-errors")]
+macro_rules! bounded_result {
+    (<$t:ty as $checked_t:tt>::$checked_op:ident) => {
+        |lhs, rhs| <$t as $checked_t>::$checked_op(lhs, rhs).ok_or(None)
+    };
+    (<$t:ty as $checked_t:tt>::$checked_op:ident, $bounded_op:ident) => {
+        <$t as $checked_t>::$bounded_op
+    };
+}
+#[cfg(feature = "rich-errors")]
+pub(crate) use bounded_result;
+
+// Unary operations overflow only in one direction, given as `$bound`
 macro_rules! impl_checked_trait_1_for {
-    ($checked_t:ty, $checked_op:ident, $t:ty) => {
+    ($checked_t:ty, $checked_op:ident, $t:ty $(; $bounded_op:ident => $bound:expr)?) => {
 
         impl $checked_t for $t {
             type Output = $t;
 
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
             fn $checked_op(self) -> Option<Self::Output> {
                 self.$checked_op()
             }
+
+            $(
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $bounded_op(self) -> Result<Self::Output, Option<OverflowBound>> {
+                    self.$checked_op().ok_or(Some($bound))
+                }
+            )?
+        }
+    }
+}
+
+// Shift amount of any type convertible to `u32` is accepted, and conversion
+// failure is treated like an overflow, same as shifting by at least the bit width.
+macro_rules! impl_checked_shift_for {
+    ($checked_t:tt, $checked_op:ident, $t:ty) => {
+
+        impl<Rhs> $checked_t<Rhs> for $t
+        where
+            Rhs: TryInto<u32>,
+        {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: Rhs) -> Option<Self::Output> {
+                rhs.try_into().ok().and_then(|rhs| self.$checked_op(rhs))
+            }
+        }
+    }
+}
+
+macro_rules! impl_checked_pow_for {
+    ($t:ty) => {
+
+        impl CheckedPow for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_pow(self, exp: u32) -> Option<Self::Output> {
+                self.checked_pow(exp)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_pow_bounded(self, exp: u32) -> Result<Self::Output, Option<OverflowBound>> {
+                self.checked_pow(exp).ok_or_else(|| {
+                    if self.saturating_pow(exp) == <$t>::MAX {
+                        Some(OverflowBound::Max)
+                    } else {
+                        Some(OverflowBound::Min)
+                    }
+                })
+            }
+        }
+    }
+}
+
+// Division that fails if it would truncate
+macro_rules! impl_checked_div_exact_for {
+    ($t:ty) => {
+
+        impl<Rhs> CheckedDivExact<Rhs> for $t
+        where
+            Rhs: TryInto<$t>,
+        {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_div_exact(self, rhs: Rhs) -> Option<Self::Output> {
+                let rhs = rhs.try_into().ok()?;
+                match self.checked_rem(rhs) {
+                    Some(0) => self.checked_div(rhs),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<D> CheckedDivExact<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_div_exact(self, rhs: Checked<$t, D>) -> Option<Self::Output> {
+                <$t as CheckedDivExact>::checked_div_exact(self, rhs.v)
+            }
+        }
+    }
+}
+
+// Absolute difference in the same type, which can only fail for signed types
+// when the difference doesn't fit, e.g. `i8::MIN` and `i8::MAX`
+macro_rules! impl_checked_abs_diff_for {
+    ($t:ty) => {
+
+        impl<Rhs> CheckedAbsDiff<Rhs> for $t
+        where
+            Rhs: TryInto<$t>,
+        {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_abs_diff(self, rhs: Rhs) -> Option<Self::Output> {
+                let rhs = rhs.try_into().ok()?;
+                self.abs_diff(rhs).try_into().ok()
+            }
+        }
+
+        impl<D> CheckedAbsDiff<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_abs_diff(self, rhs: Checked<$t, D>) -> Option<Self::Output> {
+                <$t as CheckedAbsDiff>::checked_abs_diff(self, rhs.v)
+            }
+        }
+    }
+}
+
+// Logarithms fail for zero and negative values, as well as an invalid base
+macro_rules! impl_checked_ilog_for {
+    ($t:ty) => {
+
+        impl<Base> CheckedIlog<Base> for $t
+        where
+            Base: TryInto<$t>,
+        {
+            type Output = u32;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_ilog(self, base: Base) -> Option<Self::Output> {
+                base.try_into().ok().and_then(|base| self.checked_ilog(base))
+            }
+        }
+
+        impl<D> CheckedIlog<Checked<$t, D>> for $t {
+            type Output = u32;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_ilog(self, base: Checked<$t, D>) -> Option<Self::Output> {
+                self.checked_ilog(base.v)
+            }
+        }
+
+        impl CheckedIlog2 for $t {
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_ilog2(self) -> Option<u32> {
+                self.checked_ilog2()
+            }
+        }
+
+        impl CheckedIlog10 for $t {
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_ilog10(self) -> Option<u32> {
+                self.checked_ilog10()
+            }
+        }
+    }
+}
+
+// For unsigned types at most one of the steps can overflow, and for signed
+// types overflowing in both directions cancels out, so `!=` works for both
+macro_rules! impl_carrying_for {
+    ($t:ty) => {
+
+        impl CarryingAdd for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn carrying_add(self, rhs: Self, carry: bool) -> (Self::Output, bool) {
+                let (v, o1) = self.overflowing_add(rhs);
+                let (v, o2) = v.overflowing_add(carry as $t);
+                (v, o1 != o2)
+            }
+        }
+
+        impl BorrowingSub for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self::Output, bool) {
+                let (v, o1) = self.overflowing_sub(rhs);
+                let (v, o2) = v.overflowing_sub(borrow as $t);
+                (v, o1 != o2)
+            }
+        }
+    }
+}
+
+macro_rules! impl_midpoint_for {
+    ($t:ty) => {
+
+        impl Midpoint for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn midpoint(self, rhs: Self) -> Self::Output {
+                self.midpoint(rhs)
+            }
+        }
+
+        impl<D> Midpoint<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn midpoint(self, rhs: Checked<$t, D>) -> Self::Output {
+                self.midpoint(rhs.v)
+            }
+        }
+    }
+}
+
+macro_rules! impl_overflowing_trait_2_for {
+    ($overflowing_t:tt, $overflowing_op:ident, $t:ty) => {
+
+        impl $overflowing_t for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $overflowing_op(self, rhs: Self) -> (Self::Output, bool) {
+                self.$overflowing_op(rhs)
+            }
+        }
+
+        impl<D> $overflowing_t<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $overflowing_op(self, rhs: Checked<$t, D>) -> (Self::Output, bool) {
+                self.$overflowing_op(rhs.v)
+            }
         }
     }
 }
 
 macro_rules! impl_checked_all {
     ($t:ty) => {
-        impl_checked_trait_2_for!(CheckedAdd, checked_add, $t);
-        impl_checked_trait_2_for!(CheckedSub, checked_sub, $t);
-        impl_checked_trait_2_for!(CheckedMul, checked_mul, $t);
-        impl_checked_trait_2_for!(CheckedDiv, checked_div, $t);
-        impl_checked_trait_2_for!(CheckedRem, checked_rem, $t);
-        impl_checked_trait_1_for!(CheckedNeg, checked_neg, $t);
+        impl Bounded for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        }
+
+        impl Zero for $t {
+            const ZERO: Self = 0;
+        }
+
+        impl One for $t {
+            const ONE: Self = 1;
+        }
+
+        impl_checked_trait_2_for!(CheckedAdd, checked_add, $t; Add => checked_add_detailed, checked_add_bounded, saturating_add);
+        impl_checked_trait_2_for!(CheckedSub, checked_sub, $t; Sub => checked_sub_detailed, checked_sub_bounded, saturating_sub);
+        impl_checked_trait_2_for!(CheckedMul, checked_mul, $t; Mul => checked_mul_detailed, checked_mul_bounded, saturating_mul);
+        impl_checked_trait_2_for!(CheckedDiv, checked_div, $t; Div => checked_div_detailed, checked_div_bounded, saturating_div);
+        impl_checked_trait_2_for!(CheckedRem, checked_rem, $t; Rem => checked_rem_detailed);
+        impl_checked_trait_2_for!(CheckedDivEuclid, checked_div_euclid, $t; DivEuclid => checked_div_euclid_detailed);
+        impl_checked_trait_2_for!(CheckedRemEuclid, checked_rem_euclid, $t; RemEuclid => checked_rem_euclid_detailed);
+        // Only the minimum of signed types, and anything but zero of unsigned ones
+        impl_checked_trait_1_for!(CheckedNeg, checked_neg, $t; checked_neg_bounded => if <$t>::MIN == 0 {
+            OverflowBound::Min
+        } else {
+            OverflowBound::Max
+        });
+        impl_checked_pow_for!($t);
+        impl_checked_shift_for!(CheckedShl, checked_shl, $t);
+        impl_checked_shift_for!(CheckedShr, checked_shr, $t);
+        impl_checked_div_exact_for!($t);
+        impl_checked_abs_diff_for!($t);
+        impl_checked_ilog_for!($t);
+        impl_carrying_for!($t);
+        impl_midpoint_for!($t);
+        impl_overflowing_trait_2_for!(OverflowingAdd, overflowing_add, $t);
+        impl_overflowing_trait_2_for!(OverflowingSub, overflowing_sub, $t);
+        impl_overflowing_trait_2_for!(OverflowingMul, overflowing_mul, $t);
     }
 }
 
+macro_rules! impl_narrow {
+    ($t:ty, $u:ty) => {
+
+        impl Narrow<$u> for $t {
+            fn checked_narrow(self) -> Option<$u> {
+                <$u>::try_from(self).ok()
+            }
+
+            #[allow(unused_comparisons)]
+            fn saturating_narrow(self) -> $u {
+                match <$u>::try_from(self) {
+                    Ok(v) => v,
+                    Err(_) if self < 0 => <$u>::MIN,
+                    Err(_) => <$u>::MAX,
+                }
+            }
+
+            fn wrapping_narrow(self) -> $u {
+                self as $u
+            }
+        }
+    }
+}
+
+// For floats, overflow means a result that isn't finite. Infinities went past
+// the bound of their sign, NaN and division by zero past no bound in particular.
+//
+// Only results are checked: a non-finite starting value is allowed, and
+// is left to `Checked::new_finite()` to reject.
+macro_rules! impl_checked_float_for {
+    (@op $t:ty, $zero:expr, $checked_t:ident, $checked_op:ident, $op:tt; $kind:ident => $detailed_op:ident $(, $bounded_op:ident)?) => {
+        impl $checked_t for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
+                Some(self $op rhs).filter(|res| res.is_finite())
+            }
+
+            $(
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $bounded_op(self, rhs: Self) -> Result<Self::Output, Option<OverflowBound>> {
+                    <$t as $checked_t>::$checked_op(self, rhs).ok_or_else(|| {
+                        let res = self $op rhs;
+                        if res.is_nan() || rhs == $zero {
+                            None
+                        } else if res.is_sign_negative() {
+                            Some(OverflowBound::Min)
+                        } else {
+                            Some(OverflowBound::Max)
+                        }
+                    })
+                }
+            )?
+
+            #[cfg(feature = "rich-errors")]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $detailed_op(self, rhs: Self) -> Result<Self::Output, $crate::Overflow> {
+                $crate::base_checked_ops::bounded_result!(<$t as $checked_t>::$checked_op $(, $bounded_op)?)(self, rhs)
+                    .map_err(|bound| {
+                        $crate::Overflow::new(
+                            bound,
+                            Some($crate::FailedOperation::new(
+                                stringify!($t),
+                                $crate::OperationKind::$kind,
+                                self,
+                                rhs,
+                            )),
+                        )
+                    })
+            }
+        }
+
+        impl<D> $checked_t<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: Checked<$t, D>) -> Option<Self::Output> {
+                <$t as $checked_t>::$checked_op(self, rhs.v)
+            }
+
+            $(
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $bounded_op(self, rhs: Checked<$t, D>) -> Result<Self::Output, Option<OverflowBound>> {
+                    <$t as $checked_t>::$bounded_op(self, rhs.v)
+                }
+            )?
+
+            #[cfg(feature = "rich-errors")]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $detailed_op(self, rhs: Checked<$t, D>) -> Result<Self::Output, $crate::Overflow> {
+                <$t as $checked_t>::$detailed_op(self, rhs.v)
+            }
+        }
+    };
+    ($($t:ty, $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Wrap a finite value, returning `None` for NaN or an infinity.
+                ///
+                /// Operations overflow on non-finite results, but a non-finite
+                /// starting value is accepted by [`Checked::new()`], `From`, `FromStr`,
+                /// `Deserialize` and the generators of property testing crates.
+                /// Validate values from untrusted sources with this instead.
+                pub fn new_finite(v: $t) -> Option<Self> {
+                    Some(v).filter(|v| v.is_finite()).map(Self::from)
+                }
+            }
+
+            impl_checked_float_for!(@op $t, $zero, CheckedAdd, checked_add, +; Add => checked_add_detailed, checked_add_bounded);
+            impl_checked_float_for!(@op $t, $zero, CheckedSub, checked_sub, -; Sub => checked_sub_detailed, checked_sub_bounded);
+            impl_checked_float_for!(@op $t, $zero, CheckedMul, checked_mul, *; Mul => checked_mul_detailed, checked_mul_bounded);
+            impl_checked_float_for!(@op $t, $zero, CheckedDiv, checked_div, /; Div => checked_div_detailed, checked_div_bounded);
+            impl_checked_float_for!(@op $t, $zero, CheckedRem, checked_rem, %; Rem => checked_rem_detailed);
+
+            impl CheckedNeg for $t {
+                type Output = $t;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_neg(self) -> Option<Self> {
+                    Some(-self).filter(|res| res.is_finite())
+                }
+            }
+
+            impl CheckedAbs for $t {
+                type Output = $t;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_abs(self) -> Option<Self> {
+                    let res = if self.is_sign_negative() { -self } else { self };
+                    Some(res).filter(|res| res.is_finite())
+                }
+            }
+
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+
+            impl Zero for $t {
+                const ZERO: Self = $zero;
+            }
+
+            impl One for $t {
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+#[cfg(feature = "half")]
+pub(crate) use impl_checked_float_for;
+
+macro_rules! impl_narrow_all {
+    () => {};
+    ($t:ty $(, $rest:ty)*) => {
+        $(
+            impl_narrow!($t, $rest);
+            impl_narrow!($rest, $t);
+        )*
+        impl_narrow_all!($($rest),*);
+    };
+}
+
 impl_checked_all!(usize);
 impl_checked_all!(isize);
 impl_checked_all!(u8);
@@ -101,13 +906,176 @@ impl_checked_all!(i64);
 impl_checked_all!(u128);
 impl_checked_all!(i128);
 
-// nightly only
-/*
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroIsize);
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroI8);
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroI16);
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroI32);
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroI64);
-impl_checked_trait_1_for!(CheckedAbs, checked_abs, std::num::NonZeroI128);
-*/
+impl_checked_float_for!(f32, 0.0, 1.0; f64, 0.0, 1.0);
+
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, isize; checked_abs_bounded => OverflowBound::Max);
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, i8; checked_abs_bounded => OverflowBound::Max);
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, i16; checked_abs_bounded => OverflowBound::Max);
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, i32; checked_abs_bounded => OverflowBound::Max);
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, i64; checked_abs_bounded => OverflowBound::Max);
+impl_checked_trait_1_for!(CheckedAbs, checked_abs, i128; checked_abs_bounded => OverflowBound::Max);
+
+// Types with a wider primitive; `u128` and `i128` widen into `DoubleWide`
+macro_rules! impl_widening_mul {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl WideningMul for $t {
+                type Output = $wide;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn widening_mul(self, rhs: Self) -> Self::Output {
+                    (self as $wide) * (rhs as $wide)
+                }
+            }
+        )*
+    }
+}
+
+impl_widening_mul!(
+    usize => u128, isize => i128,
+    u8 => u16, i8 => i16,
+    u16 => u32, i16 => i32,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128
+);
+
+// The product of two values always fits in the wider type
+macro_rules! impl_checked_mul_div {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl CheckedMulDiv for $t {
+                type Output = $t;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self::Output> {
+                    ((self as $wide) * (mul as $wide))
+                        .checked_div(div as $wide)
+                        .and_then(|v| v.try_into().ok())
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_mul_div!(
+    usize => u128, isize => i128,
+    u8 => u16, i8 => i16,
+    u16 => u32, i16 => i32,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128
+);
+
+// Only stable for unsigned types
+macro_rules! impl_checked_next_for {
+    ($($t:ty),*) => {
+        $(
+            impl_checked_trait_2_for!(CheckedNextMultipleOf, checked_next_multiple_of, $t);
+            impl_checked_trait_1_for!(CheckedNextPowerOfTwo, checked_next_power_of_two, $t);
+        )*
+    }
+}
+
+impl_checked_next_for!(usize, u8, u16, u32, u64, u128);
+
+// Square root of an unsigned integer always exists
+macro_rules! impl_checked_isqrt_unsigned_for {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedIsqrt for $t {
+                type Output = $t;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_isqrt(self) -> Option<Self::Output> {
+                    Some(self.isqrt())
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_isqrt_unsigned_for!(usize, u8, u16, u32, u64, u128);
+
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, isize);
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, i8);
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, i16);
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, i32);
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, i64);
+impl_checked_trait_1_for!(CheckedIsqrt, checked_isqrt, i128);
+
+impl_narrow_all!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Implement the base checked traits for a type with inherent `checked_*` methods
+///
+/// This allows a custom numeric type to be used in [`Checked`] and
+/// [`Unchecked`](crate::Unchecked), the same way the primitive types are.
+/// The inherent methods must take `self` by value, and the right hand side
+/// of binary operations must be of the same type.
+///
+/// By default `add`, `sub`, `mul`, `div` and `rem` are implemented. A subset,
+/// or additionally `div_euclid`, `rem_euclid` and `neg`, can be selected by
+/// listing them after the type.
+///
+/// ```
+/// use overflow_proof::{impl_checked_ops_for, Checked};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Cents(u32);
+///
+/// impl Cents {
+///     fn checked_add(self, rhs: Self) -> Option<Self> {
+///         self.0.checked_add(rhs.0).map(Cents)
+///     }
+///
+///     fn checked_sub(self, rhs: Self) -> Option<Self> {
+///         self.0.checked_sub(rhs.0).map(Cents)
+///     }
+/// }
+///
+/// impl_checked_ops_for!(Cents; add, sub);
+///
+/// let total = Checked::new(Cents(150)) + Cents(50) - Cents(100);
+/// assert_eq!(total.check().map(|c| *c), Some(Cents(100)));
+/// assert!((Checked::new(Cents(1)) - Cents(2)).check().is_none());
+/// ```
+#[macro_export]
+macro_rules! impl_checked_ops_for {
+    (@op $t:ty, add) => { $crate::impl_checked_ops_for!(@2 $t, CheckedAdd, checked_add); };
+    (@op $t:ty, sub) => { $crate::impl_checked_ops_for!(@2 $t, CheckedSub, checked_sub); };
+    (@op $t:ty, mul) => { $crate::impl_checked_ops_for!(@2 $t, CheckedMul, checked_mul); };
+    (@op $t:ty, div) => { $crate::impl_checked_ops_for!(@2 $t, CheckedDiv, checked_div); };
+    (@op $t:ty, rem) => { $crate::impl_checked_ops_for!(@2 $t, CheckedRem, checked_rem); };
+    (@op $t:ty, div_euclid) => { $crate::impl_checked_ops_for!(@2 $t, CheckedDivEuclid, checked_div_euclid); };
+    (@op $t:ty, rem_euclid) => { $crate::impl_checked_ops_for!(@2 $t, CheckedRemEuclid, checked_rem_euclid); };
+    (@op $t:ty, neg) => {
+        impl $crate::CheckedNeg for $t {
+            type Output = $t;
+
+            fn checked_neg(self) -> Option<Self::Output> {
+                <$t>::checked_neg(self)
+            }
+        }
+    };
+    (@2 $t:ty, $checked_t:ident, $checked_op:ident) => {
+        impl $crate::$checked_t for $t {
+            type Output = $t;
 
+            fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
+                <$t>::$checked_op(self, rhs)
+            }
+        }
+
+        impl<D> $crate::$checked_t<$crate::Checked<$t, D>> for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: $crate::Checked<$t, D>) -> Option<Self::Output> {
+                <$t>::$checked_op(self, rhs.into_inner())
+            }
+        }
+    };
+    ($t:ty) => {
+        $crate::impl_checked_ops_for!($t; add, sub, mul, div, rem);
+    };
+    ($t:ty; $($op:ident),+ $(,)?) => {
+        $( $crate::impl_checked_ops_for!(@op $t, $op); )+
+    };
+}
@@ -0,0 +1,55 @@
+use super::Checked;
+use ::bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use ::bincode::enc::{Encode, Encoder};
+use ::bincode::error::{DecodeError, EncodeError};
+
+impl<T, D> Encode for Checked<T, D>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.v.encode(encoder)
+    }
+}
+
+impl<Context, T, D> Decode<Context> for Checked<T, D>
+where
+    T: Decode<Context>,
+{
+    fn decode<De: Decoder<Context = Context>>(decoder: &mut De) -> Result<Self, DecodeError> {
+        T::decode(decoder).map(Checked::from)
+    }
+}
+
+impl<'de, Context, T, D> BorrowDecode<'de, Context> for Checked<T, D>
+where
+    T: BorrowDecode<'de, Context>,
+{
+    fn borrow_decode<De: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut De,
+    ) -> Result<Self, DecodeError> {
+        T::borrow_decode(decoder).map(Checked::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn round_trip() {
+        let config = ::bincode::config::standard();
+        let v = Checked::new_without_deref(0x1234_5678u32);
+        let mut buf = [0u8; 8];
+        let len = ::bincode::encode_into_slice(v, &mut buf, config).unwrap();
+        let mut expected = [0u8; 8];
+        assert_eq!(::bincode::encode_into_slice(0x1234_5678u32, &mut expected, config).unwrap(), len);
+        assert_eq!(buf, expected);
+
+        let (read, read_len): (Checked<u32, WithoutDeref>, _) =
+            ::bincode::decode_from_slice(&buf[..len], config).unwrap();
+        assert_eq!((read, read_len), (v, len));
+        assert!(::bincode::decode_from_slice::<Checked<u32>, _>(&buf[..1], config).is_err());
+    }
+}
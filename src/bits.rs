@@ -0,0 +1,102 @@
+use super::{Checked, Overflow, Unchecked};
+use core::ops::{Bound, RangeBounds};
+
+/// Resolve `range` of bit indices to `(start, width)`, if it is valid for `bits` wide type
+fn bit_range<R>(range: R, bits: u32) -> Option<(u32, u32)>
+where
+    R: RangeBounds<u32>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e.checked_add(1)?,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => bits,
+    };
+    if bits < end {
+        return None;
+    }
+    Some((start, end.checked_sub(start)?))
+}
+
+macro_rules! impl_bits {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Mask with the lowest `width` bits set
+                fn low_bits_mask(width: u32) -> $t {
+                    if width == 0 {
+                        0
+                    } else {
+                        <$t>::MAX >> (<$t>::BITS - width)
+                    }
+                }
+
+                /// Extract the bits in `range`, shifted down to the lowest bits
+                ///
+                /// Poisoned if `range` is not a valid range of bits of the inner type.
+                pub fn get_bits<R>(self, range: R) -> Unchecked<$t, D>
+                where
+                    R: RangeBounds<u32>,
+                {
+                    Unchecked {
+                        v: bit_range(range, <$t>::BITS).map(|(start, width)| {
+                            self.v.checked_shr(start).unwrap_or(0) & Self::low_bits_mask(width)
+                        }),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+
+                /// Replace the bits in `range` with `value`
+                ///
+                /// Poisoned if `range` is not a valid range of bits of the inner type,
+                /// or `value` doesn't fit in the width of `range`.
+                pub fn set_bits<R>(self, range: R, value: $t) -> Unchecked<$t, D>
+                where
+                    R: RangeBounds<u32>,
+                {
+                    Unchecked {
+                        v: bit_range(range, <$t>::BITS).and_then(|(start, width)| {
+                            let mask = Self::low_bits_mask(width);
+                            if value & !mask != 0 {
+                                return None;
+                            }
+                            let clear = !mask.checked_shl(start).unwrap_or(0);
+                            Some(self.v & clear | value.checked_shl(start).unwrap_or(0))
+                        }),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bits!(usize, u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_and_set_bits() {
+        let header = Checked::new(0b1011_0110u8);
+        assert_eq!(*header.get_bits(4..8).check().unwrap(), 0b1011);
+        assert_eq!(*header.get_bits(..).check().unwrap(), 0b1011_0110);
+        assert_eq!(*header.get_bits(8..8).check().unwrap(), 0);
+        assert!(header.get_bits(4..9).check().is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = header.get_bits(5..4);
+        assert!(reversed.check().is_none());
+
+        assert_eq!(*header.set_bits(0..=1, 0b01).check().unwrap(), 0b1011_0101);
+        assert_eq!(*header.set_bits(.., 0xff).check().unwrap(), 0xff);
+        assert!(header.set_bits(0..2, 0b100).check().is_none());
+        assert!(header.set_bits(7..=8, 0).check().is_none());
+    }
+}
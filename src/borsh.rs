@@ -0,0 +1,38 @@
+use super::Checked;
+use ::borsh::io::{Read, Result, Write};
+use ::borsh::{BorshDeserialize, BorshSerialize};
+
+impl<T, D> BorshSerialize for Checked<T, D>
+where
+    T: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.v.serialize(writer)
+    }
+}
+
+impl<T, D> BorshDeserialize for Checked<T, D>
+where
+    T: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        T::deserialize_reader(reader).map(Checked::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn round_trip() {
+        let v = Checked::new_without_deref(0x1234_5678u64);
+        let bytes = ::borsh::to_vec(&v).unwrap();
+        assert_eq!(bytes, ::borsh::to_vec(&0x1234_5678u64).unwrap());
+
+        let read = Checked::<u64, WithoutDeref>::try_from_slice(&bytes).unwrap();
+        assert_eq!(read, v);
+        assert!(Checked::<u64>::try_from_slice(&bytes[1..]).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use super::Checked;
+use ::bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+// SAFETY: `Checked` is `repr(transparent)` over `T`, the only other field being a ZST,
+// so it has exactly the layout and valid bit patterns of `T`.
+unsafe impl<T, D> Zeroable for Checked<T, D> where T: Zeroable {}
+
+unsafe impl<T, D> Pod for Checked<T, D>
+where
+    T: Pod,
+    D: 'static,
+{
+}
+
+unsafe impl<T, D> TransparentWrapper<T> for Checked<T, D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn cast_slice() {
+        let raw = [1u64, 2, u64::MAX];
+        let checked: &[Checked<u64, WithoutDeref>] = Checked::wrap_slice(&raw);
+        assert_eq!(checked[2], Checked::new_without_deref(u64::MAX));
+        assert!({ checked[2] + 1 }.check().is_none());
+
+        let bytes: &[u8] = ::bytemuck::cast_slice(checked);
+        assert_eq!(bytes.len(), 24);
+        let back: &[Checked<u64>] = ::bytemuck::cast_slice(bytes);
+        assert_eq!(*back[1], 2);
+
+        assert_eq!(Checked::<u32>::zeroed(), Checked::new(0));
+    }
+}
@@ -0,0 +1,95 @@
+use super::{BorrowingSub, CarryingAdd, Checked};
+
+impl<T, D> Checked<T, D> {
+    /// Add with an incoming carry, returning the wrapped sum and the outgoing carry
+    ///
+    /// Building block for arithmetic on integers made of multiple limbs,
+    /// together with [`Checked::borrowing_sub()`] and [`Checked::widening_mul()`].
+    ///
+    /// ```
+    /// use overflow_proof::{Checked, WithoutDeref};
+    ///
+    /// // 128-bit addition with 64-bit limbs, least significant first
+    /// let a = [u64::MAX, 1];
+    /// let b = [1, 2];
+    /// let mut carry = false;
+    /// let mut sum = [Checked::<u64, WithoutDeref>::new_without_deref(0); 2];
+    /// for i in 0..2 {
+    ///     let (v, c) = Checked::new_without_deref(a[i]).carrying_add(b[i], carry);
+    ///     sum[i] = v;
+    ///     carry = c;
+    /// }
+    /// assert_eq!(sum.map(Checked::into_inner), [0, 4]);
+    /// assert!(!carry);
+    /// ```
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn carrying_add<Rhs>(
+        self,
+        rhs: Rhs,
+        carry: bool,
+    ) -> (Checked<<T as CarryingAdd<Rhs>>::Output, D>, bool)
+    where
+        T: CarryingAdd<Rhs>,
+    {
+        let (v, carry) = self.v.carrying_add(rhs, carry);
+        (
+            Checked {
+                v,
+                _deref: self._deref,
+            },
+            carry,
+        )
+    }
+
+    /// Subtract with an incoming borrow, returning the wrapped difference and the outgoing borrow
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn borrowing_sub<Rhs>(
+        self,
+        rhs: Rhs,
+        borrow: bool,
+    ) -> (Checked<<T as BorrowingSub<Rhs>>::Output, D>, bool)
+    where
+        T: BorrowingSub<Rhs>,
+    {
+        let (v, borrow) = self.v.borrowing_sub(rhs, borrow);
+        (
+            Checked {
+                v,
+                _deref: self._deref,
+            },
+            borrow,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carry_and_borrow() {
+        let (v, carry) = Checked::new(u8::MAX).carrying_add(0, true);
+        assert_eq!((*v, carry), (0, true));
+        let (v, carry) = Checked::new(u8::MAX).carrying_add(u8::MAX, true);
+        assert_eq!((*v, carry), (u8::MAX, true));
+
+        let (v, borrow) = Checked::new(0u8).borrowing_sub(0, true);
+        assert_eq!((*v, borrow), (u8::MAX, true));
+        let (v, borrow) = Checked::new(5u8).borrowing_sub(3, true);
+        assert_eq!((*v, borrow), (1, false));
+
+        // Signed: the flag reports overflow of the whole operation
+        let (v, overflow) = Checked::new(i8::MAX).carrying_add(-1, true);
+        assert_eq!((*v, overflow), (i8::MAX, false));
+        let (v, overflow) = Checked::new(i8::MIN).borrowing_sub(0, true);
+        assert_eq!((*v, overflow), (i8::MAX, true));
+    }
+
+    #[test]
+    fn widening_mul_limbs() {
+        let p = Checked::new(u64::MAX).widening_mul(u64::MAX);
+        assert_eq!(*p, (u128::from(u64::MAX)) * u128::from(u64::MAX));
+        assert_eq!(*Checked::new(i8::MIN).widening_mul(i8::MIN), 16384i16);
+        assert_eq!(*p.saturating_narrow::<u64>(), u64::MAX);
+    }
+}
@@ -0,0 +1,122 @@
+use super::{Bounded, Checked, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+use ::chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone};
+
+macro_rules! impl_checked_time_delta_op {
+    ($checked_t:ident, $checked_op:ident, $rhs:ty, |$l:ident, $r:ident| $body:expr) => {
+        impl $checked_t<$rhs> for TimeDelta {
+            type Output = TimeDelta;
+
+            fn $checked_op(self, rhs: $rhs) -> Option<Self::Output> {
+                let ($l, $r) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<D> $checked_t<Checked<$rhs, D>> for TimeDelta {
+            type Output = TimeDelta;
+
+            fn $checked_op(self, rhs: Checked<$rhs, D>) -> Option<Self::Output> {
+                let ($l, $r) = (self, rhs.v);
+                $body
+            }
+        }
+    };
+}
+
+impl_checked_time_delta_op!(CheckedAdd, checked_add, TimeDelta, |l, r| {
+    TimeDelta::checked_add(&l, &r)
+});
+impl_checked_time_delta_op!(CheckedSub, checked_sub, TimeDelta, |l, r| {
+    TimeDelta::checked_sub(&l, &r)
+});
+impl_checked_time_delta_op!(CheckedMul, checked_mul, i32, |l, r| TimeDelta::checked_mul(
+    &l, r
+));
+impl_checked_time_delta_op!(CheckedDiv, checked_div, i32, |l, r| TimeDelta::checked_div(
+    &l, r
+));
+
+impl Bounded for TimeDelta {
+    const MIN: Self = TimeDelta::MIN;
+    const MAX: Self = TimeDelta::MAX;
+}
+
+impl Zero for TimeDelta {
+    const ZERO: Self = TimeDelta::zero();
+}
+
+// Timestamps are offset by a delta, so only those two operations make sense
+macro_rules! impl_checked_timestamp {
+    ($(impl$(<$tz:ident>)? for $t:ty;)*) => {
+        $(
+            impl$(<$tz: TimeZone>)? CheckedAdd<TimeDelta> for $t {
+                type Output = $t;
+
+                fn checked_add(self, rhs: TimeDelta) -> Option<Self::Output> {
+                    self.checked_add_signed(rhs)
+                }
+            }
+
+            impl<$($tz: TimeZone,)? D> CheckedAdd<Checked<TimeDelta, D>> for $t {
+                type Output = $t;
+
+                fn checked_add(self, rhs: Checked<TimeDelta, D>) -> Option<Self::Output> {
+                    self.checked_add_signed(rhs.v)
+                }
+            }
+
+            impl$(<$tz: TimeZone>)? CheckedSub<TimeDelta> for $t {
+                type Output = $t;
+
+                fn checked_sub(self, rhs: TimeDelta) -> Option<Self::Output> {
+                    self.checked_sub_signed(rhs)
+                }
+            }
+
+            impl<$($tz: TimeZone,)? D> CheckedSub<Checked<TimeDelta, D>> for $t {
+                type Output = $t;
+
+                fn checked_sub(self, rhs: Checked<TimeDelta, D>) -> Option<Self::Output> {
+                    self.checked_sub_signed(rhs.v)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_timestamp!(
+    impl<Tz> for DateTime<Tz>;
+    impl for NaiveDateTime;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::chrono::Utc;
+
+    #[test]
+    fn offsets() {
+        let week = { Checked::new(TimeDelta::days(1)) * 7 }
+            .check()
+            .expect("no overflow");
+        let start = DateTime::<Utc>::from_timestamp(0, 0).unwrap();
+        let end = { Checked::new(start) + week - TimeDelta::hours(1) }
+            .check()
+            .expect("no overflow");
+        assert_eq!(
+            *end,
+            DateTime::<Utc>::from_timestamp(7 * 86_400 - 3_600, 0).unwrap()
+        );
+
+        assert!({ Checked::new(start) + Checked::<TimeDelta>::MAX }
+            .check()
+            .is_none());
+        assert!({ Checked::<TimeDelta>::MIN - TimeDelta::seconds(1) }
+            .check()
+            .is_none());
+        assert!({ week / 0 }.check().is_none());
+        assert!({ Checked::new(NaiveDateTime::MAX) + TimeDelta::seconds(1) }
+            .check()
+            .is_none());
+    }
+}
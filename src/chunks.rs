@@ -0,0 +1,65 @@
+use super::{Checked, Overflow, Unchecked};
+
+macro_rules! impl_chunks {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Number of chunks of `chunk_size` needed to cover a length of `self`
+                ///
+                /// Poisoned if `chunk_size` is zero.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn chunks_needed(self, chunk_size: $t) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: self.v.checked_div(chunk_size).and_then(|full| {
+                            let partial = self.v.checked_rem(chunk_size)? != 0;
+                            Some(full + partial as $t)
+                        }),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+
+                /// Length of the last chunk when splitting a length of `self`
+                /// into chunks of `chunk_size`
+                ///
+                /// Zero if `self` is zero. Poisoned if `chunk_size` is zero.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn last_chunk_len(self, chunk_size: $t) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: self.v.checked_rem(chunk_size).map(|rem| {
+                            if rem == 0 && self.v != 0 {
+                                chunk_size
+                            } else {
+                                rem
+                            }
+                        }),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_chunks!(usize, u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        let len = Checked::new(usize::MAX);
+        assert_eq!(*len.chunks_needed(2).check().unwrap(), usize::MAX / 2 + 1);
+        assert_eq!(*len.last_chunk_len(2).check().unwrap(), 1);
+        assert!(len.chunks_needed(0).check().is_none());
+        assert!(len.last_chunk_len(0).check().is_none());
+
+        let len = Checked::new(4096u32);
+        assert_eq!(*len.chunks_needed(512).check().unwrap(), 8);
+        assert_eq!(*len.last_chunk_len(512).check().unwrap(), 512);
+        assert_eq!(*Checked::new(0u32).chunks_needed(512).check().unwrap(), 0);
+        assert_eq!(*Checked::new(0u32).last_chunk_len(512).check().unwrap(), 0);
+    }
+}
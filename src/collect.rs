@@ -0,0 +1,44 @@
+use super::{Checked, Unchecked};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// Collecting [`Unchecked`] values checks all of them, failing if any overflowed
+///
+/// Stops at the first overflow.
+impl<T, D> FromIterator<Unchecked<T, D>> for Option<Vec<Checked<T, D>>> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Unchecked<T, D>>,
+    {
+        iter.into_iter().map(Unchecked::check).collect()
+    }
+}
+
+/// Checking a whole batch of [`Unchecked`] values at once
+///
+/// ```
+/// use overflow_proof::{Checked, TryCollectChecked};
+///
+/// let prices = [100u32, 250, 40];
+/// let with_tax = prices.iter().map(|p| Checked::new(*p) * 123 / 100);
+/// assert_eq!(
+///     with_tax.try_collect_checked().map(|v| v.len()),
+///     Some(3)
+/// );
+///
+/// let doubled = [u32::MAX, 1].iter().map(|p| Checked::new(*p) * 2);
+/// assert!(doubled.try_collect_checked().is_none());
+/// ```
+pub trait TryCollectChecked<T, D> {
+    /// Check all values, returning `None` if any overflowed
+    fn try_collect_checked(self) -> Option<Vec<Checked<T, D>>>;
+}
+
+impl<I, T, D> TryCollectChecked<T, D> for I
+where
+    I: Iterator<Item = Unchecked<T, D>>,
+{
+    fn try_collect_checked(self) -> Option<Vec<Checked<T, D>>> {
+        self.collect()
+    }
+}
@@ -0,0 +1,73 @@
+use super::{forget_bound, Unchecked};
+
+impl<T, D> Unchecked<T, D> {
+    /// Transform the value with an infallible step, keeping overflow
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// let cents = Checked::new(1_999u64) * 3;
+    /// let dollars = cents.map(|c| (c + 50) / 100);
+    /// assert_eq!(*dollars.check().unwrap(), 60);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Unchecked<U, D>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.then(forget_bound, |v| Unchecked::from(f(v)))
+    }
+
+    /// Transform the value with a fallible step, where `None` counts as overflow
+    ///
+    /// Lets custom `checked_*` style functions participate in the chain.
+    pub fn and_then<U, F>(self, f: F) -> Unchecked<U, D>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        self.then(forget_bound, |v| Unchecked::from(f(v)))
+    }
+
+    /// Combine with another value, overflowing if either of them did
+    pub fn zip<U, D2>(self, other: Unchecked<U, D2>) -> Unchecked<(T, U), D> {
+        self.then(forget_bound, |v| other.then(forget_bound, |u| Unchecked::from((v, u))))
+    }
+
+    /// Call `f` with a reference to the value, if it didn't overflow
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Some(v) = &self.v {
+            f(v);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Checked;
+
+    #[test]
+    fn combinators() {
+        let mut seen = None;
+        let total = (Checked::new(200u8) + 50)
+            .inspect(|v| seen = Some(*v))
+            .and_then(|v| v.checked_next_power_of_two())
+            .zip(Checked::new(1u8) - 0);
+        assert_eq!(seen, Some(250));
+        assert!(total.check().is_none());
+
+        let pair = (Checked::new(3u8) * 2)
+            .zip(Checked::new(4u16) - 1)
+            .map(|(a, b)| u16::from(a) * b);
+        assert_eq!(*pair.check().unwrap(), 18);
+
+        let mut called = false;
+        let res = (Checked::new(0u8) - 1)
+            .inspect(|_| called = true)
+            .map(|v| v + 1);
+        assert!(!called);
+        assert!(res.check().is_none());
+    }
+}
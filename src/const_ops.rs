@@ -0,0 +1,105 @@
+use super::Checked;
+use core::marker::PhantomData;
+
+macro_rules! impl_const_op {
+    ($t:ty; $($method:ident, $checked_method:ident, $op:literal);*) => {
+        impl<D> Checked<$t, D> {
+            $(
+                #[doc = concat!("`", $op, "` usable in `const` contexts, returning `None` on overflow.")]
+                ///
+                /// Operators can't be called in `const` yet, so this stands in for them
+                /// when building tables of constants.
+                pub const fn $method(self, rhs: $t) -> Option<Self> {
+                    match self.v.$checked_method(rhs) {
+                        Some(v) => Some(Self {
+                            v,
+                            _deref: PhantomData,
+                        }),
+                        None => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! impl_const_ops {
+    ($($t:ty),*) => {
+        $(
+            impl_const_op!($t;
+                const_add, checked_add, "+";
+                const_sub, checked_sub, "-";
+                const_mul, checked_mul, "*"
+            );
+        )*
+    };
+}
+
+impl_const_ops!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Evaluate an integer expression at compile time into a [`Checked`] value
+///
+/// Overflow, including division by zero, fails the build instead of
+/// resulting in overflow at runtime.
+///
+/// ```
+/// use overflow_proof::{const_checked, Checked, WithoutDeref};
+///
+/// const LIMIT: Checked<u32> = const_checked!(1024 * 1024 * 512);
+/// assert_eq!(*LIMIT, 512 << 20);
+///
+/// const PAGE: Checked<u16, WithoutDeref> = const_checked!(1 << 12);
+/// assert_eq!(PAGE.into_inner(), 4096);
+/// ```
+///
+/// ```compile_fail
+/// use overflow_proof::{const_checked, Checked};
+///
+/// let limit: Checked<u32> = const_checked!(1024 * 1024 * 4096);
+/// ```
+#[macro_export]
+macro_rules! const_checked {
+    ($e:expr) => {
+        $crate::Checked::from_const(const { $e })
+    };
+}
+
+impl<T, D> Checked<T, D> {
+    #[doc(hidden)]
+    pub const fn from_const(v: T) -> Self {
+        Self {
+            v,
+            _deref: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    const KIB: Checked<u32, WithoutDeref> = Checked::new_without_deref(1024);
+    const LIMITS: [Option<Checked<u32, WithoutDeref>>; 3] = [
+        KIB.const_mul(1024),
+        KIB.const_sub(1025),
+        KIB.const_add(u32::MAX),
+    ];
+    const RAW: u32 = KIB.into_inner();
+
+    #[test]
+    fn const_eval() {
+        assert_eq!(LIMITS[0], Some(Checked::new_without_deref(1024 * 1024)));
+        assert_eq!(LIMITS[1], None);
+        assert_eq!(LIMITS[2], None);
+        assert_eq!(RAW, 1024);
+        assert_eq!(Checked::new(-3i8).const_mul(-43), None);
+    }
+
+    #[test]
+    fn const_checked() {
+        let v: Checked<i64> = const_checked!(-(1 << 40) / 3);
+        assert_eq!(*v, -(1 << 40) / 3);
+        assert!({ v * (1 << 30) }.check().is_none());
+    }
+}
@@ -0,0 +1,150 @@
+use super::{Checked, CheckedAdd, WithoutDeref};
+use std::collections::hash_map::{self, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Addition to a [`CheckedCounter`] rejected because the total for `key` would overflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterOverflow<K, T> {
+    /// Key whose total would overflow
+    pub key: K,
+    /// The rejected delta
+    pub delta: T,
+}
+
+impl<K, T> fmt::Display for CounterOverflow<K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "total for {:?} would overflow when adding {:?}",
+            self.key, self.delta
+        )
+    }
+}
+
+impl<K, T> std::error::Error for CounterOverflow<K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+}
+
+/// Per-key totals, accumulated with checked addition
+///
+/// Additions that would overflow the total of a key are rejected
+/// and leave that total unchanged.
+///
+/// ```
+/// use overflow_proof::CheckedCounter;
+///
+/// let mut bytes_per_user = CheckedCounter::new();
+/// bytes_per_user.add("alice", 200u8).expect("no overflow");
+/// bytes_per_user.add("bob", 100).expect("no overflow");
+///
+/// let err = bytes_per_user.add("alice", 100).unwrap_err();
+/// assert_eq!(err.key, "alice");
+/// assert_eq!(bytes_per_user.get(&"alice").map(|v| v.into_inner()), Some(200));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheckedCounter<K, T> {
+    totals: HashMap<K, Checked<T, WithoutDeref>>,
+}
+
+impl<K, T> Default for CheckedCounter<K, T> {
+    fn default() -> Self {
+        Self {
+            totals: HashMap::new(),
+        }
+    }
+}
+
+impl<K, T> CheckedCounter<K, T> {
+    /// Create an empty counter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of keys with a total
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    /// Are there no keys with a total?
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Iterate over keys and their totals
+    pub fn iter(&self) -> hash_map::Iter<'_, K, Checked<T, WithoutDeref>> {
+        self.totals.iter()
+    }
+
+    /// Consume the counter, returning the totals
+    pub fn into_totals(self) -> HashMap<K, Checked<T, WithoutDeref>> {
+        self.totals
+    }
+}
+
+impl<K, T> CheckedCounter<K, T>
+where
+    K: Eq + Hash,
+{
+    /// Total for `key`, if anything was added to it
+    pub fn get(&self, key: &K) -> Option<&Checked<T, WithoutDeref>> {
+        self.totals.get(key)
+    }
+}
+
+impl<K, T> CheckedCounter<K, T>
+where
+    K: Eq + Hash,
+    T: Clone + CheckedAdd<Output = T>,
+{
+    /// Add `delta` to the total for `key`
+    ///
+    /// On overflow, the total is left unchanged.
+    pub fn add(&mut self, key: K, delta: T) -> Result<(), CounterOverflow<K, T>> {
+        if let Some(total) = self.totals.get_mut(&key) {
+            return match total.v.clone().checked_add(delta.clone()) {
+                Some(v) => {
+                    total.v = v;
+                    Ok(())
+                }
+                None => Err(CounterOverflow { key, delta }),
+            };
+        }
+        self.totals.insert(
+            key,
+            Checked {
+                v: delta,
+                _deref: PhantomData,
+            },
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow_keeps_total() {
+        let mut counter = CheckedCounter::new();
+        counter.add(1u32, i8::MIN).expect("no overflow");
+        assert_eq!(
+            counter.add(1, -1),
+            Err(CounterOverflow { key: 1, delta: -1 })
+        );
+        counter.add(1, 1).expect("no overflow");
+        counter.add(2, -1).expect("no overflow");
+
+        assert_eq!(counter.len(), 2);
+        assert_eq!(counter.get(&1).map(|v| v.into_inner()), Some(-127));
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Checked, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub};
+use core::cmp::Ordering;
+
+/// Sign and magnitude of any primitive integer, which all primitive
+/// integers can be losslessly converted to.
+trait SignMagnitude: Copy {
+    fn sign_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_sign_magnitude_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl SignMagnitude for $t {
+                fn sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_sign_magnitude_signed {
+    ($($t:ty),*) => {
+        $(
+            impl SignMagnitude for $t {
+                fn sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, (self as i128).unsigned_abs())
+                }
+            }
+        )*
+    }
+}
+
+impl_sign_magnitude_unsigned!(usize, u8, u16, u32, u64, u128);
+impl_sign_magnitude_signed!(isize, i8, i16, i32, i64, i128);
+
+fn cross_width_cmp<A: SignMagnitude, B: SignMagnitude>(a: A, b: B) -> Ordering {
+    match (a.sign_magnitude(), b.sign_magnitude()) {
+        ((false, a), (false, b)) => a.cmp(&b),
+        ((true, a), (true, b)) => b.cmp(&a),
+        ((true, _), (false, _)) => Ordering::Less,
+        ((false, _), (true, _)) => Ordering::Greater,
+    }
+}
+
+macro_rules! impl_cross_width_cmp {
+    ($t:ty, $u:ty) => {
+        impl<D1, D2> PartialEq<Checked<$u, D1>> for Checked<$t, D2> {
+            fn eq(&self, other: &Checked<$u, D1>) -> bool {
+                cross_width_cmp(self.v, other.v) == Ordering::Equal
+            }
+        }
+
+        impl<D1, D2> PartialOrd<Checked<$u, D1>> for Checked<$t, D2> {
+            fn partial_cmp(&self, other: &Checked<$u, D1>) -> Option<Ordering> {
+                Some(cross_width_cmp(self.v, other.v))
+            }
+        }
+    };
+}
+
+macro_rules! impl_cross_width_cmp_all {
+    () => {};
+    ($t:ty $(, $rest:ty)*) => {
+        $(
+            impl_cross_width_cmp!($t, $rest);
+            impl_cross_width_cmp!($rest, $t);
+        )*
+        impl_cross_width_cmp_all!($($rest),*);
+    };
+}
+
+impl_cross_width_cmp_all!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Lossless widening of a pair of types to the wider of the two
+///
+/// Implemented for pairs of primitive integers where one can represent
+/// all values of the other. Arithmetic operations between [`Checked`] values
+/// of such types will be performed in (and return) the wider type.
+pub trait Widen<Rhs>: Sized {
+    type Output: From<Self> + From<Rhs>;
+}
+
+macro_rules! impl_widen {
+    ($t:ty => $($wide:ty),*) => {
+        $(
+            impl Widen<$wide> for $t {
+                type Output = $wide;
+            }
+
+            impl Widen<$t> for $wide {
+                type Output = $wide;
+            }
+        )*
+    };
+}
+
+impl_widen!(u8 => u16, u32, u64, u128, i16, i32, i64, i128);
+impl_widen!(u16 => u32, u64, u128, i32, i64, i128);
+impl_widen!(u32 => u64, u128, i64, i128);
+impl_widen!(u64 => u128, i128);
+impl_widen!(i8 => i16, i32, i64, i128);
+impl_widen!(i16 => i32, i64, i128);
+impl_widen!(i32 => i64, i128);
+impl_widen!(i64 => i128);
+
+macro_rules! impl_widening_op {
+    ($checked_t:tt, $checked_op:ident) => {
+        impl<T, U, D> $checked_t<Checked<U, D>> for T
+        where
+            T: Widen<U>,
+            <T as Widen<U>>::Output: $checked_t<Output = <T as Widen<U>>::Output>,
+        {
+            type Output = <T as Widen<U>>::Output;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: Checked<U, D>) -> Option<Self::Output> {
+                Self::Output::from(self).$checked_op(Self::Output::from(rhs.v))
+            }
+        }
+    };
+}
+
+impl_widening_op!(CheckedAdd, checked_add);
+impl_widening_op!(CheckedSub, checked_sub);
+impl_widening_op!(CheckedMul, checked_mul);
+impl_widening_op!(CheckedDiv, checked_div);
+impl_widening_op!(CheckedRem, checked_rem);
@@ -0,0 +1,53 @@
+use super::{Bounded, CheckedAbs, CheckedNeg, One, Zero};
+use ::rust_decimal::Decimal;
+
+crate::impl_checked_ops_for!(Decimal);
+
+// Negation and `abs()` only flip the sign bit, so they never fail
+impl CheckedNeg for Decimal {
+    type Output = Decimal;
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl CheckedAbs for Decimal {
+    type Output = Decimal;
+
+    fn checked_abs(self) -> Option<Self> {
+        Some(self.abs())
+    }
+}
+
+impl Bounded for Decimal {
+    const MIN: Self = Decimal::MIN;
+    const MAX: Self = Decimal::MAX;
+}
+
+impl Zero for Decimal {
+    const ZERO: Self = Decimal::ZERO;
+}
+
+impl One for Decimal {
+    const ONE: Self = Decimal::ONE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Checked;
+
+    #[test]
+    fn price_times_quantity() {
+        let price = Checked::new_without_deref(Decimal::new(1999, 2));
+        let total = { price * Decimal::from(3) - Decimal::new(50, 2) }
+            .check()
+            .expect("no overflow");
+        assert_eq!(total.into_inner(), Decimal::new(5947, 2));
+
+        assert!({ Checked::<Decimal>::MAX * Decimal::from(2) }.check().is_none());
+        assert!({ price / Decimal::ZERO }.check().is_none());
+        assert_eq!(*{ -Checked::new(Decimal::ONE) }.abs().check().unwrap(), Decimal::ONE);
+    }
+}
@@ -0,0 +1,332 @@
+use super::{forget_bound, Checked, CheckedMulDiv, Narrow, Overflow, Unchecked, WideningMul};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+/// Integer twice as wide as `T`, for types with no wider primitive
+///
+/// Stored as two limbs in two's-complement form, with the `lo` limb holding
+/// the bit pattern of the lower half. Produced by [`WideningMul`] for
+/// `u128` and `i128`, and converted back with [`Narrow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoubleWide<T> {
+    hi: T,
+    lo: T,
+}
+
+impl<T> DoubleWide<T>
+where
+    T: Copy,
+{
+    /// Upper limb, including the sign for signed types
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// Lower limb, as the bit pattern of the lower half
+    ///
+    /// For signed types it's negative if its top bit is set, regardless of
+    /// the sign of the whole value.
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+}
+
+const HALF_MASK: u128 = u64::MAX as u128;
+
+/// Full 256-bit product of two `u128`, as `(hi, lo)`
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+fn mul_u128(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a & HALF_MASK);
+    let (b1, b0) = (b >> 64, b & HALF_MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    // Can't overflow: each term is below 2^64
+    let mid = (p00 >> 64) + (p01 & HALF_MASK) + (p10 & HALF_MASK);
+    let lo = (p00 & HALF_MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divide 256-bit `(hi, lo)` by `div`, if the quotient fits in `u128`
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+fn div_u256_by_u128(hi: u128, lo: u128, div: u128) -> Option<u128> {
+    if div == 0 || div <= hi {
+        return None;
+    }
+    let mut rem = hi;
+    let mut quot = 0u128;
+    for i in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> i) & 1);
+        if carry == 1 || div <= rem {
+            rem = rem.wrapping_sub(div);
+            quot |= 1 << i;
+        }
+    }
+    Some(quot)
+}
+
+impl WideningMul for u128 {
+    type Output = DoubleWide<u128>;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn widening_mul(self, rhs: Self) -> Self::Output {
+        let (hi, lo) = mul_u128(self, rhs);
+        DoubleWide { hi, lo }
+    }
+}
+
+impl WideningMul for i128 {
+    type Output = DoubleWide<i128>;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn widening_mul(self, rhs: Self) -> Self::Output {
+        let (hi, lo) = mul_u128(self.unsigned_abs(), rhs.unsigned_abs());
+        let (hi, lo) = if (self < 0) != (rhs < 0) {
+            // Two's-complement negation across both limbs
+            let (lo, borrow) = 0u128.overflowing_sub(lo);
+            (0u128.wrapping_sub(hi).wrapping_sub(borrow as u128), lo)
+        } else {
+            (hi, lo)
+        };
+        DoubleWide {
+            hi: hi as i128,
+            lo: lo as i128,
+        }
+    }
+}
+
+impl CheckedMulDiv for u128 {
+    type Output = u128;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self::Output> {
+        let (hi, lo) = mul_u128(self, mul);
+        div_u256_by_u128(hi, lo, div)
+    }
+}
+
+impl CheckedMulDiv for i128 {
+    type Output = i128;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self::Output> {
+        let (hi, lo) = mul_u128(self.unsigned_abs(), mul.unsigned_abs());
+        let quot = div_u256_by_u128(hi, lo, div.unsigned_abs())?;
+        if ((self < 0) != (mul < 0)) != (div < 0) {
+            0i128.checked_sub_unsigned(quot)
+        } else {
+            i128::try_from(quot).ok()
+        }
+    }
+}
+
+impl Narrow<u128> for DoubleWide<u128> {
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn checked_narrow(self) -> Option<u128> {
+        if self.hi == 0 {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn saturating_narrow(self) -> u128 {
+        self.checked_narrow().unwrap_or(u128::MAX)
+    }
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn wrapping_narrow(self) -> u128 {
+        self.lo
+    }
+}
+
+impl Narrow<i128> for DoubleWide<i128> {
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn checked_narrow(self) -> Option<i128> {
+        // Fits if the upper limb is just the sign extension of the lower one
+        if self.hi == self.lo >> 127 {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn saturating_narrow(self) -> i128 {
+        match self.checked_narrow() {
+            Some(v) => v,
+            None if self.hi < 0 => i128::MIN,
+            None => i128::MAX,
+        }
+    }
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn wrapping_narrow(self) -> i128 {
+        self.lo
+    }
+}
+
+impl PartialOrd for DoubleWide<u128> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DoubleWide<u128> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hi, self.lo).cmp(&(other.hi, other.lo))
+    }
+}
+
+impl PartialOrd for DoubleWide<i128> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DoubleWide<i128> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The lower limb holds unsigned bits of the value
+        (self.hi, self.lo as u128).cmp(&(other.hi, other.lo as u128))
+    }
+}
+
+impl<T, D> Checked<T, D> {
+    /// Multiply into a type twice as wide, which can't overflow
+    ///
+    /// The result can be converted back with [`Checked::narrow()`].
+    pub fn widening_mul<Rhs>(self, rhs: Rhs) -> Checked<<T as WideningMul<Rhs>>::Output, D>
+    where
+        T: WideningMul<Rhs>,
+    {
+        Checked {
+            v: self.v.widening_mul(rhs),
+            _deref: self._deref,
+        }
+    }
+
+    /// Calculate `self * mul / div` with a double-width intermediate product
+    ///
+    /// Fails only if the final quotient doesn't fit, or on division by zero.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn mul_div<Rhs>(self, mul: Rhs, div: Rhs) -> Unchecked<<T as CheckedMulDiv<Rhs>>::Output, D>
+    where
+        T: CheckedMulDiv<Rhs>,
+    {
+        Unchecked {
+            v: self.v.checked_mul_div(mul, div),
+            overflow: Overflow::default(),
+            _deref: self._deref,
+        }
+    }
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Calculate `self * mul / div` with a double-width intermediate product
+    ///
+    /// Fails only if the final quotient doesn't fit, or on division by zero.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn mul_div<Rhs>(self, mul: Rhs, div: Rhs) -> Unchecked<<T as CheckedMulDiv<Rhs>>::Output, D>
+    where
+        T: CheckedMulDiv<Rhs>,
+    {
+        self.then(forget_bound, |v| Unchecked::from(v.checked_mul_div(mul, div)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_mul_u128() {
+        let max = Checked::new(u128::MAX);
+        let sq = max.widening_mul(u128::MAX);
+        assert_eq!(
+            sq.into_inner(),
+            DoubleWide {
+                hi: u128::MAX - 1,
+                lo: 1
+            }
+        );
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        assert_eq!(sq.into_inner().hi(), u128::MAX - 1);
+        assert_eq!(sq.into_inner().lo(), 1);
+        assert!(sq.narrow::<u128>().check().is_none());
+        assert_eq!(*sq.saturating_narrow::<u128>(), u128::MAX);
+        assert_eq!(
+            *Checked::new(3u128)
+                .widening_mul(5)
+                .narrow::<u128>()
+                .check()
+                .unwrap(),
+            15
+        );
+    }
+
+    #[test]
+    fn widening_mul_i128() {
+        let min = Checked::new(i128::MIN);
+        assert!(min.widening_mul(-1).narrow::<i128>().check().is_none());
+        assert_eq!(
+            *min.widening_mul(1).narrow::<i128>().check().unwrap(),
+            i128::MIN
+        );
+        assert_eq!(
+            *Checked::new(-3i128)
+                .widening_mul(5)
+                .narrow::<i128>()
+                .check()
+                .unwrap(),
+            -15
+        );
+        assert_eq!(*min.widening_mul(2).saturating_narrow::<i128>(), i128::MIN);
+
+        // -1 * 1 is all ones in both limbs, 2^127 * 2^127 is 2^254
+        let minus_one = Checked::new(-1i128).widening_mul(1).into_inner();
+        assert_eq!((minus_one.hi(), minus_one.lo()), (-1, -1));
+        let sq = min.widening_mul(i128::MIN).into_inner();
+        assert_eq!((sq.hi(), sq.lo()), (1 << 126, 0));
+    }
+
+    #[test]
+    fn mul_div() {
+        let amount = Checked::new(u128::MAX / 3);
+        assert_eq!(*amount.mul_div(6, 4).check().unwrap(), u128::MAX / 2);
+        assert!(amount.mul_div(6, 1).check().is_none());
+        assert!(amount.mul_div(1, 0).check().is_none());
+
+        assert_eq!(
+            *Checked::new(i128::MAX).mul_div(-2, 2).check().unwrap(),
+            -i128::MAX
+        );
+        assert_eq!(
+            *Checked::new(i128::MIN).mul_div(3, 3).check().unwrap(),
+            i128::MIN
+        );
+        assert!(Checked::new(i128::MIN).mul_div(-1, 1).check().is_none());
+        assert_eq!(*Checked::new(-7i128).mul_div(1, 2).check().unwrap(), -3);
+    }
+
+    #[test]
+    fn mul_div_narrow() {
+        let fee_ppm = 2_500u64;
+        let amount = Checked::new(u64::MAX / 2);
+        assert_eq!(
+            *amount.mul_div(fee_ppm, 1_000_000).check().unwrap(),
+            (u128::from(u64::MAX / 2) * 2_500 / 1_000_000) as u64
+        );
+        assert!(amount.mul_div(3, 1).check().is_none());
+        assert!(amount.mul_div(3, 0).check().is_none());
+
+        assert_eq!(*Checked::new(i8::MIN).mul_div(-100, -100).check().unwrap(), i8::MIN);
+        assert!(Checked::new(i8::MIN).mul_div(-1, 1).check().is_none());
+        assert!({ Checked::new(200u8) + 100 }.mul_div(1, 2).check().is_none());
+    }
+}
@@ -0,0 +1,125 @@
+use super::{
+    Bounded, Checked, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Overflow, OverflowBound,
+    Unchecked, Zero,
+};
+use core::convert::TryFrom;
+use core::time::Duration;
+
+macro_rules! impl_duration_units {
+    ($($as_u64:ident, $as_u128:ident, $from:ident, $unit:literal);*) => {
+        impl<D> Checked<Duration, D> {
+            $(
+                #[doc = concat!("Total number of whole ", $unit, ", failing if it doesn't fit in `u64`")]
+                pub fn $as_u64(self) -> Unchecked<u64, D> {
+                    Unchecked {
+                        v: u64::try_from(self.v.$as_u128()).ok(),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+
+                #[doc = concat!("Create a duration from a number of ", $unit)]
+                pub fn $from(v: Checked<u64, D>) -> Self {
+                    Checked {
+                        v: Duration::$from(v.v),
+                        _deref: v._deref,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_duration_units!(
+    as_millis_u64, as_millis, from_millis, "milliseconds";
+    as_micros_u64, as_micros, from_micros, "microseconds";
+    as_nanos_u64, as_nanos, from_nanos, "nanoseconds"
+);
+
+// Durations only go out of range at the top, except when subtracting
+macro_rules! impl_checked_duration_op {
+    ($checked_t:ident, $checked_op:ident, $bounded_op:ident, $rhs:ty, $bound:expr) => {
+        impl $checked_t<$rhs> for Duration {
+            type Output = Duration;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: $rhs) -> Option<Self::Output> {
+                Duration::$checked_op(self, rhs)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $bounded_op(self, rhs: $rhs) -> Result<Self::Output, Option<OverflowBound>> {
+                Duration::$checked_op(self, rhs).ok_or($bound)
+            }
+        }
+
+        impl<D> $checked_t<Checked<$rhs, D>> for Duration {
+            type Output = Duration;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $checked_op(self, rhs: Checked<$rhs, D>) -> Option<Self::Output> {
+                Duration::$checked_op(self, rhs.v)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $bounded_op(self, rhs: Checked<$rhs, D>) -> Result<Self::Output, Option<OverflowBound>> {
+                <Duration as $checked_t<$rhs>>::$bounded_op(self, rhs.v)
+            }
+        }
+    };
+}
+
+impl_checked_duration_op!(CheckedAdd, checked_add, checked_add_bounded, Duration, Some(OverflowBound::Max));
+impl_checked_duration_op!(CheckedSub, checked_sub, checked_sub_bounded, Duration, Some(OverflowBound::Min));
+impl_checked_duration_op!(CheckedMul, checked_mul, checked_mul_bounded, u32, Some(OverflowBound::Max));
+// Division can only fail by dividing by zero
+impl_checked_duration_op!(CheckedDiv, checked_div, checked_div_bounded, u32, None);
+
+impl Bounded for Duration {
+    const MIN: Self = Duration::ZERO;
+    const MAX: Self = Duration::MAX;
+}
+
+impl Zero for Duration {
+    const ZERO: Self = Duration::ZERO;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_conversions() {
+        let d = Checked::new(Duration::from_secs(3));
+        assert_eq!(*d.as_millis_u64().check().unwrap(), 3_000);
+        assert_eq!(*d.as_nanos_u64().check().unwrap(), 3_000_000_000);
+
+        let max = Checked::new(Duration::MAX);
+        assert!(max.as_micros_u64().check().is_none());
+        assert_eq!(
+            Checked::<Duration>::from_micros(Checked::new(1_500)).into_inner(),
+            Duration::from_micros(1_500)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let timeout = Checked::new_without_deref(Duration::from_millis(250));
+        let total = { timeout * 4 + Duration::from_secs(1) - Duration::from_millis(125) }
+            .check()
+            .expect("no overflow");
+        assert_eq!(total.into_inner(), Duration::from_millis(1_875));
+        assert_eq!({ total / 3 }.check().map(Checked::into_inner), Some(Duration::from_millis(625)));
+
+        assert!({ timeout - Duration::from_secs(1) }.check().is_none());
+        assert_eq!(
+            { Checked::<Duration>::MAX * 2 - Duration::from_secs(1) }.saturate(),
+            Some(Checked::<Duration>::MAX)
+        );
+        assert_eq!(
+            { timeout - Duration::from_secs(1) }.saturate(),
+            Some(Checked::new_without_deref(Duration::ZERO))
+        );
+        assert!({ timeout / 0 }.saturate().is_none());
+    }
+}
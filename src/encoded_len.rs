@@ -0,0 +1,80 @@
+use super::{Checked, Overflow, Unchecked};
+
+impl<D> Checked<usize, D> {
+    /// Length of the hex encoding of `self` bytes
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn hex_encoded_len(self) -> Unchecked<usize, D> {
+        Unchecked {
+            v: self.v.checked_mul(2),
+            overflow: Overflow::default(),
+            _deref: self._deref,
+        }
+    }
+
+    /// Length of the padded base64 encoding of `self` bytes
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn base64_encoded_len(self) -> Unchecked<usize, D> {
+        let groups = self.v / 3 + !self.v.is_multiple_of(3) as usize;
+        Unchecked {
+            v: groups.checked_mul(4),
+            overflow: Overflow::default(),
+            _deref: self._deref,
+        }
+    }
+
+    /// Length of the base64 encoding of `self` bytes, without padding
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn base64_unpadded_encoded_len(self) -> Unchecked<usize, D> {
+        let rem_len = match self.v % 3 {
+            0 => 0,
+            1 => 2,
+            _ => 3,
+        };
+        Unchecked {
+            v: (self.v / 3)
+                .checked_mul(4)
+                .and_then(|len| len.checked_add(rem_len)),
+            overflow: Overflow::default(),
+            _deref: self._deref,
+        }
+    }
+
+    /// Worst case length of escaping `self` bytes, where a single
+    /// byte is escaped to at most `max_escape_len` bytes
+    ///
+    /// For example 6 for JSON strings (`\u00XX`), or 4 for Rust byte strings (`\xXX`).
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn escaped_len(self, max_escape_len: usize) -> Unchecked<usize, D> {
+        Unchecked {
+            v: self.v.checked_mul(max_escape_len),
+            overflow: Overflow::default(),
+            _deref: self._deref,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded_len() {
+        let len = Checked::new(5usize);
+        assert_eq!(*len.hex_encoded_len().check().unwrap(), 10);
+        assert_eq!(*len.base64_encoded_len().check().unwrap(), 8);
+        assert_eq!(*len.base64_unpadded_encoded_len().check().unwrap(), 7);
+        assert_eq!(*len.escaped_len(6).check().unwrap(), 30);
+
+        let len = Checked::new(usize::MAX);
+        assert!(len.hex_encoded_len().check().is_none());
+        assert!(len.base64_encoded_len().check().is_none());
+        assert!(len.base64_unpadded_encoded_len().check().is_none());
+        assert_eq!(
+            *Checked::new(usize::MAX / 4 * 3)
+                .base64_encoded_len()
+                .check()
+                .unwrap(),
+            usize::MAX / 4 * 4
+        );
+    }
+}
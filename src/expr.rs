@@ -0,0 +1,438 @@
+use super::{Checked, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, WithDeref};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use core::fmt::Write;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Rem => "%",
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+#[derive(Debug)]
+enum Node<T> {
+    Value {
+        name: Option<&'static str>,
+        v: T,
+    },
+    Op {
+        op: Op,
+        // Only taken when dropped
+        operands: Option<Operands<T>>,
+    },
+}
+
+// Length of the rendered text, after which it is cut short
+const MAX_RENDERED_LEN: usize = 1024;
+
+type Operands<T> = (Rc<Node<T>>, Rc<Node<T>>);
+
+// Sub-expressions are shared, so they are identified by address
+fn key<T>(node: &Node<T>) -> *const Node<T> {
+    node
+}
+
+// Part of the rendered expression still to be written
+enum Piece<'a, T> {
+    Node { node: &'a Node<T>, nested: bool },
+    Str(&'static str),
+    Op(Op),
+}
+
+impl<T> Node<T> {
+    fn operands(&self) -> Option<(Op, &Node<T>, &Node<T>)> {
+        match self {
+            Node::Value { .. } => None,
+            Node::Op { op, operands } => {
+                let (lhs, rhs) = operands.as_ref().expect("taken only when dropped");
+                Some((*op, lhs, rhs))
+            }
+        }
+    }
+
+    fn take_operands(&mut self, nodes: &mut Vec<Rc<Node<T>>>) {
+        if let Node::Op { operands, .. } = self {
+            if let Some((lhs, rhs)) = operands.take() {
+                nodes.push(lhs);
+                nodes.push(rhs);
+            }
+        }
+    }
+}
+
+// Dropped iteratively, as a long chain of operations would overflow the stack
+impl<T> Drop for Node<T> {
+    fn drop(&mut self) {
+        let mut nodes = Vec::new();
+        self.take_operands(&mut nodes);
+        while let Some(node) = nodes.pop() {
+            // Still used elsewhere if it can't be unwrapped
+            if let Ok(mut node) = Rc::try_unwrap(node) {
+                node.take_operands(&mut nodes);
+            }
+        }
+    }
+}
+
+impl<T> Node<T>
+where
+    T: fmt::Debug,
+{
+    // Written with an explicit stack, for the same reason as `drop`.
+    // Shared sub-expressions are written out at every use, so the text could
+    // grow exponentially, and is cut short at `MAX_RENDERED_LEN`.
+    fn rendered(&self) -> String {
+        let mut s = String::new();
+        let mut pieces = vec![Piece::Node {
+            node: self,
+            nested: false,
+        }];
+        while let Some(piece) = pieces.pop() {
+            if MAX_RENDERED_LEN < s.len() {
+                s.push_str("...");
+                break;
+            }
+            match piece {
+                Piece::Str(part) => s.push_str(part),
+                Piece::Op(op) => write!(s, " {} ", op).expect("writing to a `String`"),
+                Piece::Node {
+                    node: Node::Value { name: Some(name), .. },
+                    ..
+                } => s.push_str(name),
+                Piece::Node {
+                    node: Node::Value { name: None, v },
+                    ..
+                } => write!(s, "{:?}", v).expect("writing to a `String`"),
+                Piece::Node { node, nested } => {
+                    let (op, lhs, rhs) = node.operands().expect("not a value");
+                    if nested {
+                        pieces.push(Piece::Str(")"));
+                    }
+                    pieces.push(Piece::Node {
+                        node: rhs,
+                        nested: true,
+                    });
+                    pieces.push(Piece::Op(op));
+                    pieces.push(Piece::Node {
+                        node: lhs,
+                        nested: true,
+                    });
+                    if nested {
+                        pieces.push(Piece::Str("("));
+                    }
+                }
+            }
+        }
+        s
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Clone
+        + fmt::Debug
+        + CheckedAdd<Output = T>
+        + CheckedSub<Output = T>
+        + CheckedMul<Output = T>
+        + CheckedDiv<Output = T>
+        + CheckedRem<Output = T>,
+{
+    // Each sub-expression is evaluated once, however many times it's used,
+    // with an explicit stack of nodes whose operands are evaluated first.
+    fn eval(&self) -> Result<T, ExprError> {
+        let mut values = BTreeMap::new();
+        let mut nodes = vec![(self, false)];
+        while let Some((node, operands_done)) = nodes.pop() {
+            if values.contains_key(&key(node)) {
+                continue;
+            }
+            let (op, lhs, rhs) = match node {
+                Node::Value { v, .. } => {
+                    values.insert(key(node), v.clone());
+                    continue;
+                }
+                _ => node.operands().expect("not a value"),
+            };
+            if !operands_done {
+                nodes.push((node, true));
+                nodes.push((rhs, false));
+                nodes.push((lhs, false));
+                continue;
+            }
+
+            let (l, r) = (&values[&key(lhs)], &values[&key(rhs)]);
+            let res = match op {
+                Op::Add => l.clone().checked_add(r.clone()),
+                Op::Sub => l.clone().checked_sub(r.clone()),
+                Op::Mul => l.clone().checked_mul(r.clone()),
+                Op::Div => l.clone().checked_div(r.clone()),
+                Op::Rem => l.clone().checked_rem(r.clone()),
+            };
+            match res {
+                Some(v) => {
+                    values.insert(key(node), v);
+                }
+                None => {
+                    return Err(ExprError {
+                        expr: node.rendered(),
+                        lhs: format!("{:?}", l),
+                        op: op.symbol(),
+                        rhs: format!("{:?}", r),
+                    })
+                }
+            }
+        }
+        Ok(values.remove(&key(self)).expect("evaluated last"))
+    }
+}
+
+/// Arithmetic expression that records its operations, to explain overflows
+///
+/// Unlike [`Unchecked`](super::Unchecked), which only tracks whether overflow
+/// happened, `Expr` keeps the whole tree of operations and operand values,
+/// and evaluates it lazily in [`Expr::check()`]. On overflow the returned
+/// [`ExprError`] points at the failing sub-expression.
+///
+/// Sub-expressions are reference counted, so cloning an `Expr` to reuse it
+/// in multiple places is cheap, and it's evaluated only once. When rendered,
+/// it's written out at every use, and text longer than 1024 bytes is cut short.
+///
+/// ```
+/// use overflow_proof::Expr;
+///
+/// let price = Expr::named("price", 200u8);
+/// let qty = Expr::named("qty", 3u8);
+/// let fee = Expr::named("fee", 5u8);
+///
+/// let err = (price * qty - fee).check().unwrap_err();
+/// assert_eq!(err.expression(), "price * qty");
+/// assert_eq!(err.to_string(), "overflow in `price * qty`: 200 * 3");
+/// ```
+#[derive(Debug)]
+pub struct Expr<T, D = WithDeref> {
+    node: Rc<Node<T>>,
+    _deref: PhantomData<D>,
+}
+
+impl<T, D> Clone for Expr<T, D> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node.clone(),
+            _deref: self._deref,
+        }
+    }
+}
+
+impl<T> Expr<T, WithDeref> {
+    /// Create an unnamed leaf value
+    pub fn new(v: T) -> Self {
+        Self::leaf(None, v)
+    }
+
+    /// Create a leaf value, that will be referred to by `name` in explanations
+    pub fn named(name: &'static str, v: T) -> Self {
+        Self::leaf(Some(name), v)
+    }
+}
+
+impl<T, D> Expr<T, D> {
+    /// Create a leaf from a [`Checked`] value, that will be referred to by `name` in explanations
+    pub fn named_checked(name: &'static str, v: Checked<T, D>) -> Self {
+        Self::leaf(Some(name), v.v)
+    }
+
+    fn leaf(name: Option<&'static str>, v: T) -> Self {
+        Self::from_node(Node::Value { name, v })
+    }
+
+    fn from_node(node: Node<T>) -> Self {
+        Self {
+            node: Rc::new(node),
+            _deref: PhantomData,
+        }
+    }
+
+    fn op(self, op: Op, rhs: Self) -> Self {
+        Self::from_node(Node::Op {
+            op,
+            operands: Some((self.node, rhs.node)),
+        })
+    }
+}
+
+impl<T, D> Expr<T, D>
+where
+    T: Clone
+        + fmt::Debug
+        + CheckedAdd<Output = T>
+        + CheckedSub<Output = T>
+        + CheckedMul<Output = T>
+        + CheckedDiv<Output = T>
+        + CheckedRem<Output = T>,
+{
+    /// Evaluate the expression
+    ///
+    /// Returns an error describing the first sub-expression that overflowed.
+    pub fn check(&self) -> Result<Checked<T, D>, ExprError> {
+        self.node.eval().map(|v| Checked {
+            v,
+            _deref: PhantomData,
+        })
+    }
+}
+
+impl<T, D> fmt::Display for Expr<T, D>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.node.rendered())
+    }
+}
+
+impl<T, D> From<Checked<T, D>> for Expr<T, D> {
+    fn from(v: Checked<T, D>) -> Self {
+        Self::leaf(None, v.v)
+    }
+}
+
+macro_rules! impl_expr_op {
+    ($op:tt, $method:ident) => {
+        impl<T, D> $op for Expr<T, D> {
+            type Output = Expr<T, D>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.op(Op::$op, rhs)
+            }
+        }
+
+        impl<T, D> $op<T> for Expr<T, D> {
+            type Output = Expr<T, D>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                self.op(Op::$op, Expr::leaf(None, rhs))
+            }
+        }
+    };
+}
+
+impl_expr_op!(Add, add);
+impl_expr_op!(Sub, sub);
+impl_expr_op!(Mul, mul);
+impl_expr_op!(Div, div);
+impl_expr_op!(Rem, rem);
+
+/// Overflow detected when evaluating an [`Expr`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    expr: String,
+    lhs: String,
+    op: &'static str,
+    rhs: String,
+}
+
+impl ExprError {
+    /// The rendered sub-expression that overflowed
+    pub fn expression(&self) -> &str {
+        &self.expr
+    }
+
+    /// Values of the operands of the failing operation
+    pub fn operands(&self) -> (&str, &str) {
+        (&self.lhs, &self.rhs)
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "overflow in `{}`: {} {} {}",
+            self.expr, self.lhs, self.op, self.rhs
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExprError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_nested_overflow() {
+        let a = Expr::named("a", 200u8);
+        let b = Expr::named("b", 2u8);
+
+        let total = (a.clone() + 1) * b.clone() - a;
+        assert_eq!(total.to_string(), "((a + 1) * b) - a");
+
+        let err = total.check().unwrap_err();
+        assert_eq!(err.expression(), "(a + 1) * b");
+        assert_eq!(err.operands(), ("201", "2"));
+
+        assert_eq!(*(b.clone() * b + 1).check().expect("no overflow"), 5);
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let err = (Expr::<u32>::new(1) / 0).check().unwrap_err();
+        assert_eq!(err.to_string(), "overflow in `1 / 0`: 1 / 0");
+    }
+
+    #[test]
+    fn shared_sub_expressions() {
+        // Each doubling reuses the previous expression twice
+        let mut x = Expr::new(1u64);
+        for _ in 0..63 {
+            x = x.clone() + x;
+        }
+        assert_eq!(*x.check().expect("no overflow"), 1 << 63);
+
+        let err = (x.clone() + x).check().unwrap_err();
+        assert_eq!(err.operands(), ("9223372036854775808", "9223372036854775808"));
+    }
+
+    #[test]
+    fn long_chain() {
+        let mut sum = Expr::new(0u64);
+        for i in 0..100_000 {
+            sum = sum + i;
+        }
+        assert_eq!(*sum.check().expect("no overflow"), 4_999_950_000);
+        assert!(sum.to_string().starts_with("(((((("));
+        assert!(sum.to_string().ends_with("..."));
+
+        let err = (sum * u64::MAX).check().unwrap_err();
+        assert_eq!(err.operands(), ("4999950000", "18446744073709551615"));
+    }
+}
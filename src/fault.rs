@@ -0,0 +1,128 @@
+use std::cell::Cell;
+
+/// When arithmetic operations should fail, regardless of their operands
+///
+/// Installed for the current thread with [`inject_faults()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Every operation overflows
+    Always,
+    /// Only the `n`-th operation (counting from 1) overflows
+    Nth(u64),
+    /// Each operation overflows with probability `1 / one_in`,
+    /// in a sequence that is reproducible for a given `seed`
+    Random { seed: u64, one_in: u64 },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    fault: Fault,
+    ops: u64,
+}
+
+thread_local! {
+    static STATE: Cell<Option<State>> = const { Cell::new(None) };
+}
+
+/// Restores the previous fault injection configuration when dropped
+///
+/// Returned by [`inject_faults()`].
+#[derive(Debug)]
+#[must_use = "fault injection is disabled again when the guard is dropped"]
+pub struct FaultGuard {
+    prev: Option<State>,
+}
+
+impl Drop for FaultGuard {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        let _ = STATE.try_with(|state| state.set(prev));
+    }
+}
+
+/// Make arithmetic operations on the current thread fail according to `fault`
+///
+/// Affects operators and methods of [`Checked`](crate::Checked) and
+/// [`Unchecked`](crate::Unchecked) until the returned guard is dropped,
+/// which allows exercising overflow handling without contrived inputs.
+/// Since the configuration is per thread, tests running in parallel
+/// don't affect each other.
+///
+/// ```
+/// use overflow_proof::{inject_faults, Checked, Fault};
+///
+/// let _guard = inject_faults(Fault::Nth(2));
+/// assert!((Checked::new(1u32) + 1).check().is_some());
+/// assert!((Checked::new(1u32) + 1).check().is_none());
+/// assert!((Checked::new(1u32) + 1).check().is_some());
+/// ```
+pub fn inject_faults(fault: Fault) -> FaultGuard {
+    FaultGuard {
+        prev: STATE.with(|state| state.replace(Some(State { fault, ops: 0 }))),
+    }
+}
+
+/// SplitMix64, which is good enough to decide when to fail
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Poison the result of an operation, if an installed [`Fault`] says so
+pub(crate) fn inject<T>(res: Option<T>) -> Option<T> {
+    res.filter(|_| {
+        STATE
+            .try_with(|state| {
+                let mut s = match state.get() {
+                    Some(s) => s,
+                    None => return true,
+                };
+                s.ops = s.ops.wrapping_add(1);
+                state.set(Some(s));
+                match s.fault {
+                    Fault::Always => false,
+                    Fault::Nth(n) => s.ops != n,
+                    Fault::Random { seed, one_in } => {
+                        one_in == 0 || !splitmix64(seed ^ splitmix64(s.ops)).is_multiple_of(one_in)
+                    }
+                }
+            })
+            .unwrap_or(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Checked;
+
+    #[test]
+    fn guard_restores_previous() {
+        let outer = inject_faults(Fault::Always);
+        {
+            let _inner = inject_faults(Fault::Nth(5));
+            assert!((Checked::new(1u8) + 1).check().is_some());
+        }
+        assert!((Checked::new(1u8) + 1).check().is_none());
+        drop(outer);
+        assert!((Checked::new(1u8) + 1).check().is_some());
+    }
+
+    #[test]
+    fn random_is_reproducible() {
+        let run = || {
+            let _guard = inject_faults(Fault::Random {
+                seed: 42,
+                one_in: 3,
+            });
+            (0..100)
+                .map(|_| (Checked::new(1u64) * 2).check().is_none())
+                .collect::<Vec<_>>()
+        };
+        let failures = run();
+        assert_eq!(failures, run());
+        assert!(failures.iter().any(|f| *f) && !failures.iter().all(|f| *f));
+    }
+}
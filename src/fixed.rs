@@ -0,0 +1,108 @@
+use super::{
+    Bounded, Checked, CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem,
+    CheckedSub, Zero,
+};
+use ::fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use ::fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+
+macro_rules! impl_checked_op_fixed {
+    ($t:ident, $le:ident, $checked_t:ident, $checked_op:ident) => {
+        impl<Frac: $le> $checked_t for $t<Frac> {
+            type Output = $t<Frac>;
+
+            fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
+                <$t<Frac>>::$checked_op(self, rhs)
+            }
+        }
+
+        impl<Frac: $le, D> $checked_t<Checked<$t<Frac>, D>> for $t<Frac> {
+            type Output = $t<Frac>;
+
+            fn $checked_op(self, rhs: Checked<$t<Frac>, D>) -> Option<Self::Output> {
+                <$t<Frac>>::$checked_op(self, rhs.v)
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_fixed {
+    ($($t:ident, $le:ident);*) => {
+        $(
+            impl_checked_op_fixed!($t, $le, CheckedAdd, checked_add);
+            impl_checked_op_fixed!($t, $le, CheckedSub, checked_sub);
+            impl_checked_op_fixed!($t, $le, CheckedMul, checked_mul);
+            impl_checked_op_fixed!($t, $le, CheckedDiv, checked_div);
+            impl_checked_op_fixed!($t, $le, CheckedRem, checked_rem);
+
+            impl<Frac: $le> CheckedNeg for $t<Frac> {
+                type Output = $t<Frac>;
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t<Frac>>::checked_neg(self)
+                }
+            }
+
+            impl<Frac: $le> Bounded for $t<Frac> {
+                const MIN: Self = <$t<Frac>>::MIN;
+                const MAX: Self = <$t<Frac>>::MAX;
+            }
+
+            impl<Frac: $le> Zero for $t<Frac> {
+                const ZERO: Self = <$t<Frac>>::ZERO;
+            }
+        )*
+    };
+}
+
+impl_checked_fixed!(
+    FixedU8, LeEqU8; FixedI8, LeEqU8;
+    FixedU16, LeEqU16; FixedI16, LeEqU16;
+    FixedU32, LeEqU32; FixedI32, LeEqU32;
+    FixedU64, LeEqU64; FixedI64, LeEqU64;
+    FixedU128, LeEqU128; FixedI128, LeEqU128
+);
+
+macro_rules! impl_checked_abs_fixed {
+    ($($t:ident, $le:ident);*) => {
+        $(
+            impl<Frac: $le> CheckedAbs for $t<Frac> {
+                type Output = $t<Frac>;
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t<Frac>>::checked_abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_abs_fixed!(
+    FixedI8, LeEqU8; FixedI16, LeEqU16; FixedI32, LeEqU32; FixedI64, LeEqU64; FixedI128, LeEqU128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::fixed::types::{I16F16, U8F8};
+
+    #[test]
+    fn fixed_point() {
+        let gain = Checked::new(I16F16::from_num(1.5));
+        let out = { gain * I16F16::from_num(-3) + I16F16::from_num(0.25) }
+            .check()
+            .expect("no overflow");
+        assert_eq!(*out, I16F16::from_num(-4.25));
+
+        assert!({ Checked::new(U8F8::from_num(200)) * U8F8::from_num(2) }
+            .check()
+            .is_none());
+        assert!({ Checked::<U8F8>::ZERO - U8F8::from_num(1) }
+            .check()
+            .is_none());
+        assert!({ gain / I16F16::ZERO }.check().is_none());
+        assert!(Checked::<I16F16>::MIN.abs().check().is_none());
+    }
+}
@@ -0,0 +1,74 @@
+use super::Checked;
+
+macro_rules! impl_write_to {
+    ($($t:ty, $max_len:expr, |$v:ident| $neg:expr, $mag:expr);* $(;)?) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Maximum length of the decimal representation written by [`Checked::write_to()`]
+                pub const MAX_STR_LEN: usize = $max_len;
+
+                /// Format as decimal into `buf`, without allocating.
+                ///
+                /// Returns the written part of `buf`, or `None` if `buf`
+                /// is too short (see [`Checked::MAX_STR_LEN`]).
+                pub fn write_to<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {
+                    let mut digits = [0u8; $max_len];
+                    let mut pos = digits.len();
+                    let $v = self.v;
+                    let neg = $neg;
+                    let mut mag = $mag;
+                    loop {
+                        pos -= 1;
+                        digits[pos] = b'0' + (mag % 10) as u8;
+                        mag /= 10;
+                        if mag == 0 {
+                            break;
+                        }
+                    }
+                    if neg {
+                        pos -= 1;
+                        digits[pos] = b'-';
+                    }
+
+                    let digits = &digits[pos..];
+                    let out = buf.get_mut(..digits.len())?;
+                    out.copy_from_slice(digits);
+                    core::str::from_utf8(out).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_write_to!(
+    usize, 20, |v| false, v;
+    isize, 20, |v| v < 0, v.unsigned_abs();
+    u8, 3, |v| false, v;
+    i8, 4, |v| v < 0, v.unsigned_abs();
+    u16, 5, |v| false, v;
+    i16, 6, |v| v < 0, v.unsigned_abs();
+    u32, 10, |v| false, v;
+    i32, 11, |v| v < 0, v.unsigned_abs();
+    u64, 20, |v| false, v;
+    i64, 20, |v| v < 0, v.unsigned_abs();
+    u128, 39, |v| false, v;
+    i128, 40, |v| v < 0, v.unsigned_abs();
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_to() {
+        let mut buf = [0u8; Checked::<i128>::MAX_STR_LEN];
+        assert_eq!(Checked::new(0u8).write_to(&mut buf), Some("0"));
+        assert_eq!(Checked::new(u64::MAX).write_to(&mut buf), Some("18446744073709551615"));
+        assert_eq!(
+            Checked::new(i128::MIN).write_to(&mut buf),
+            Some("-170141183460469231731687303715884105728")
+        );
+        assert_eq!(Checked::new(-5i8).write_to(&mut buf), Some("-5"));
+        assert_eq!(Checked::new(1000u32).write_to(&mut buf[..3]), None);
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Checked, Unchecked};
+
+/// [`Unchecked`] value that panics if dropped overflowed without being checked
+///
+/// Returned by [`Unchecked::guard()`]. Unlike `Unchecked` it is not `Copy`,
+/// so a copy can't be dropped while another one is checked. Meant for tests
+/// and debug builds, to catch silently ignored overflows.
+///
+/// ```should_panic
+/// use overflow_proof::Checked;
+///
+/// let total = (Checked::new(u8::MAX) + 1).guard();
+/// // dropped here without being checked
+/// ```
+#[derive(Debug)]
+#[must_use = "overflow is only detected when the value is checked"]
+pub struct Guarded<T, D> {
+    inner: Option<Unchecked<T, D>>,
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Wrap in a [`Guarded`], panicking if dropped overflowed without being checked
+    pub fn guard(self) -> Guarded<T, D> {
+        Guarded { inner: Some(self) }
+    }
+}
+
+impl<T, D> Guarded<T, D> {
+    /// Convert back to [`Checked`], disarming the guard.
+    ///
+    /// Returns `None` if inner value denotes overflow.
+    #[must_use]
+    pub fn check(self) -> Option<Checked<T, D>> {
+        self.into_unchecked().check()
+    }
+
+    /// Take the [`Unchecked`] value out, disarming the guard
+    pub fn into_unchecked(mut self) -> Unchecked<T, D> {
+        self.inner.take().expect("only taken once")
+    }
+}
+
+impl<T, D> Drop for Guarded<T, D> {
+    fn drop(&mut self) {
+        // Panicking again while unwinding would abort
+        if let Some(inner) = self.inner.take() {
+            if inner.check().is_none() && !std::thread::panicking() {
+                panic!("overflowed `Unchecked` dropped without being checked");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard() {
+        let dropped = std::panic::catch_unwind(|| {
+            let _ = (Checked::new(u8::MAX) + 1).guard();
+        });
+        assert!(dropped.is_err());
+
+        let _ = (Checked::new(1u8) + 1).guard();
+        assert!((Checked::new(u8::MAX) + 1).guard().check().is_none());
+
+        let sum = (Checked::new(u8::MAX) + 1).guard().into_unchecked();
+        let copy = sum;
+        assert!(sum.check().is_none() && copy.check().is_none());
+    }
+}
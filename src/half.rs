@@ -0,0 +1,46 @@
+use super::base_checked_ops::impl_checked_float_for;
+use super::{
+    Bounded, Checked, CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg,
+    CheckedRem, CheckedSub, One, OverflowBound, Zero,
+};
+use ::half::{bf16, f16};
+
+impl_checked_float_for!(
+    f16, f16::ZERO, f16::ONE;
+    bf16, bf16::ZERO, bf16::ONE;
+);
+
+#[cfg(feature = "rich-errors")]
+impl From<f16> for crate::Operand {
+    fn from(v: f16) -> Self {
+        crate::Operand::Float(v.into())
+    }
+}
+
+#[cfg(feature = "rich-errors")]
+impl From<bf16> for crate::Operand {
+    fn from(v: bf16) -> Self {
+        crate::Operand::Float(v.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_finite() {
+        let x = Checked::new(f16::from_f32(1.5));
+        let y = { x * f16::from_f32(4.0) - f16::ONE }.check().expect("finite");
+        assert_eq!(*y, f16::from_f32(5.0));
+
+        let big = { Checked::<f16>::MAX * f16::from_f32(2.0) };
+        assert_eq!(big.saturate(), Some(Checked::<f16>::MAX));
+        let small = { Checked::<bf16>::MIN - bf16::MAX };
+        assert_eq!(small.saturate(), Some(Checked::<bf16>::MIN));
+
+        assert!({ x / f16::ZERO }.saturate().is_none());
+        assert!({ Checked::new(f16::ZERO) % f16::ZERO }.check().is_none());
+        assert_eq!(*{ -x }.abs().check().unwrap(), *x);
+    }
+}
@@ -0,0 +1,95 @@
+use super::{Checked, CheckedAdd, CheckedMul, Unchecked};
+
+impl<T, D> Checked<T, D>
+where
+    T: Clone,
+{
+    /// Would adding `rhs` succeed without overflow?
+    ///
+    /// Doesn't consume the value, so it can be used to test before committing a change.
+    pub fn fits_add<Rhs>(&self, rhs: Rhs) -> bool
+    where
+        T: CheckedAdd<Rhs>,
+    {
+        self.v.clone().checked_add(rhs).is_some()
+    }
+
+    /// Would multiplying by `rhs` succeed without overflow?
+    ///
+    /// Doesn't consume the value, so it can be used to test before committing a change.
+    pub fn fits_mul<Rhs>(&self, rhs: Rhs) -> bool
+    where
+        T: CheckedMul<Rhs>,
+    {
+        self.v.clone().checked_mul(rhs).is_some()
+    }
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Did an overflow occur?
+    pub fn is_overflow(&self) -> bool {
+        self.v.is_none()
+    }
+
+    /// Is the value free of overflow?
+    pub fn is_valid(&self) -> bool {
+        self.v.is_some()
+    }
+
+    /// Reference to the value, or `None` on overflow
+    pub fn as_option(&self) -> Option<&T> {
+        self.v.as_ref()
+    }
+}
+
+macro_rules! impl_headroom {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Distance to the maximum value of the inner type
+                pub fn headroom(&self) -> Checked<$u, D> {
+                    Checked {
+                        v: <$t>::MAX.abs_diff(self.v),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_headroom!(
+    usize => usize, isize => usize,
+    u8 => u8, i8 => u8,
+    u16 => u16, i16 => u16,
+    u32 => u32, i32 => u32,
+    u64 => u64, i64 => u64,
+    u128 => u128, i128 => u128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headroom() {
+        let v = Checked::new(250u8);
+        assert_eq!(v.headroom(), Checked::new(5u8));
+        assert!(v.fits_add(5));
+        assert!(!v.fits_add(6));
+        assert!(!v.fits_mul(2));
+        assert_eq!(Checked::new(i8::MIN).headroom(), Checked::new(255u8));
+    }
+
+    #[test]
+    fn overflow_predicates() {
+        let ok = Checked::new(250u8) + 5;
+        assert!(ok.is_valid() && !ok.is_overflow());
+        assert_eq!(ok.as_option(), Some(&255));
+
+        let overflow = ok + 1;
+        assert!(overflow.is_overflow() && !overflow.is_valid());
+        assert_eq!(overflow.as_option(), None);
+        assert!(overflow.check().is_none());
+    }
+}
@@ -0,0 +1,146 @@
+use super::Checked;
+use std::io;
+
+macro_rules! read_checked_method {
+    ($method:ident, $t:ty, $from_bytes:ident) => {
+        /// Read a
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// from the stream into a [`Checked`]
+        fn $method<D>(&mut self) -> io::Result<Checked<$t, D>> {
+            let mut buf = [0u8; std::mem::size_of::<$t>()];
+            self.read_exact(&mut buf)?;
+            Ok(Checked::from(<$t>::$from_bytes(buf)))
+        }
+    };
+}
+
+macro_rules! write_checked_method {
+    ($method:ident, $t:ty, $to_bytes:ident) => {
+        /// Write a
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// from a [`Checked`] to the stream
+        fn $method<D>(&mut self, v: Checked<$t, D>) -> io::Result<()> {
+            self.write_all(&v.v.$to_bytes())
+        }
+    };
+}
+
+macro_rules! define_io_ext {
+    ($(($t:ty, $read_le:ident, $read_be:ident, $write_le:ident, $write_be:ident)),*) => {
+        /// Extension of [`io::Read`] reading integers directly into [`Checked`] values
+        ///
+        /// ```
+        /// use overflow_proof::{Checked, ReadCheckedExt};
+        ///
+        /// let mut wire: &[u8] = &[1, 0, 0, 0, 0xff];
+        /// let len: Checked<u32> = wire.read_checked_u32_le().unwrap();
+        /// let tag: Checked<u8> = wire.read_checked_u8().unwrap();
+        /// assert!((len + tag).check().is_some());
+        /// ```
+        pub trait ReadCheckedExt: io::Read {
+            read_checked_method!(read_checked_u8, u8, from_ne_bytes);
+            read_checked_method!(read_checked_i8, i8, from_ne_bytes);
+            $(
+                read_checked_method!($read_le, $t, from_le_bytes);
+                read_checked_method!($read_be, $t, from_be_bytes);
+            )*
+        }
+
+        /// Extension of [`io::Write`] writing [`Checked`] values as integers
+        pub trait WriteCheckedExt: io::Write {
+            write_checked_method!(write_checked_u8, u8, to_ne_bytes);
+            write_checked_method!(write_checked_i8, i8, to_ne_bytes);
+            $(
+                write_checked_method!($write_le, $t, to_le_bytes);
+                write_checked_method!($write_be, $t, to_be_bytes);
+            )*
+        }
+    };
+}
+
+define_io_ext!(
+    (
+        u16,
+        read_checked_u16_le,
+        read_checked_u16_be,
+        write_checked_u16_le,
+        write_checked_u16_be
+    ),
+    (
+        i16,
+        read_checked_i16_le,
+        read_checked_i16_be,
+        write_checked_i16_le,
+        write_checked_i16_be
+    ),
+    (
+        u32,
+        read_checked_u32_le,
+        read_checked_u32_be,
+        write_checked_u32_le,
+        write_checked_u32_be
+    ),
+    (
+        i32,
+        read_checked_i32_le,
+        read_checked_i32_be,
+        write_checked_i32_le,
+        write_checked_i32_be
+    ),
+    (
+        u64,
+        read_checked_u64_le,
+        read_checked_u64_be,
+        write_checked_u64_le,
+        write_checked_u64_be
+    ),
+    (
+        i64,
+        read_checked_i64_le,
+        read_checked_i64_be,
+        write_checked_i64_le,
+        write_checked_i64_be
+    ),
+    (
+        u128,
+        read_checked_u128_le,
+        read_checked_u128_be,
+        write_checked_u128_le,
+        write_checked_u128_be
+    ),
+    (
+        i128,
+        read_checked_i128_le,
+        read_checked_i128_be,
+        write_checked_i128_le,
+        write_checked_i128_be
+    )
+);
+
+impl<R> ReadCheckedExt for R where R: io::Read + ?Sized {}
+
+impl<W> WriteCheckedExt for W where W: io::Write + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WithDeref, WithoutDeref};
+
+    #[test]
+    fn round_trip() {
+        let mut buf = Vec::new();
+        buf.write_checked_u16_be(Checked::new(0x1234)).unwrap();
+        buf.write_checked_i64_le(Checked::new_without_deref(-2))
+            .unwrap();
+        assert_eq!(&buf[..2], &[0x12, 0x34]);
+
+        let mut r = &buf[..];
+        assert_eq!(*r.read_checked_u16_be::<WithDeref>().unwrap(), 0x1234);
+        let v: Checked<i64, WithoutDeref> = r.read_checked_i64_le().unwrap();
+        assert_eq!(v, Checked::new_without_deref(-2));
+        assert_eq!(
+            r.read_checked_u8::<WithDeref>().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use super::{Checked, Overflow, Unchecked};
+use core::marker::PhantomData;
+
+/// Compensated summation of an iterator of [`Checked`] floats
+///
+/// ```
+/// use overflow_proof::{Checked, KahanSum};
+///
+/// let values = vec![Checked::new(0.1f64); 10];
+/// assert_eq!(*values.iter().copied().kahan_sum().check().unwrap(), 1.0);
+///
+/// let values = [Checked::new(f64::MAX), Checked::new(f64::MAX)];
+/// assert!(values.iter().copied().kahan_sum().check().is_none());
+/// ```
+pub trait KahanSum<T, D> {
+    /// Sum the values, compensating for the loss of precision
+    ///
+    /// Uses the Kahan-Babuška (Neumaier) algorithm. Poisoned if any value,
+    /// or the sum at any point, is NaN or infinite.
+    fn kahan_sum(self) -> Unchecked<T, D>;
+}
+
+macro_rules! impl_kahan_sum {
+    ($($t:ty),*) => {
+        $(
+            impl<I, D> KahanSum<$t, D> for I
+            where
+                I: Iterator<Item = Checked<$t, D>>,
+            {
+                fn kahan_sum(self) -> Unchecked<$t, D> {
+                    let mut sum: $t = 0.0;
+                    let mut compensation: $t = 0.0;
+                    for v in self {
+                        let v = v.v;
+                        let t = sum + v;
+                        if !t.is_finite() {
+                            return Unchecked {
+                                v: None,
+                                overflow: Overflow::default(),
+                                _deref: PhantomData,
+                            };
+                        }
+                        if v.abs() <= sum.abs() {
+                            compensation += (sum - t) + v;
+                        } else {
+                            compensation += (v - t) + sum;
+                        }
+                        sum = t;
+                    }
+                    let total = sum + compensation;
+                    Unchecked {
+                        v: Some(total).filter(|total| total.is_finite()),
+                        overflow: Overflow::default(),
+                        _deref: PhantomData,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_kahan_sum!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compensates() {
+        let values = [1.0f64, 1e100, 1.0, -1e100].map(Checked::new);
+        assert_eq!(*values.iter().copied().kahan_sum().check().unwrap(), 2.0);
+
+        let values = [Checked::new(1.0f32), Checked::new(f32::NAN)];
+        assert!(values.iter().copied().kahan_sum().check().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+use super::{Checked, CheckedAdd, CheckedSub, OverflowBound, WithoutDeref};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Change to the balance of a [`Ledger`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry<T> {
+    /// Increase the balance
+    Credit(T),
+    /// Decrease the balance
+    Debit(T),
+}
+
+/// [`Entry`] rejected by a [`Ledger`], because applying it would overflow or underflow the balance
+///
+/// Also holds the bound of the balance type that would have been crossed, if known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RejectedEntry<T>(pub Entry<T>, pub Option<OverflowBound>);
+
+impl<T> fmt::Display for RejectedEntry<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.1 {
+            Some(OverflowBound::Max) => "overflow",
+            Some(OverflowBound::Min) => "underflow",
+            None => "overflow or underflow",
+        };
+        write!(f, "entry rejected due to {}: {:?}", reason, self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for RejectedEntry<T> where T: fmt::Debug {}
+
+/// Running balance that credit and debit entries are applied to
+///
+/// Entries that would overflow or underflow the balance are rejected
+/// and leave the balance unchanged. Optionally, rejected entries are
+/// recorded for later inspection.
+///
+/// ```
+/// use overflow_proof::{Checked, Entry, Ledger};
+///
+/// let mut ledger = Ledger::with_rejected_log(Checked::new_without_deref(100u64));
+/// ledger.credit(50).expect("no overflow");
+/// assert!(ledger.debit(200).is_err());
+/// ledger.apply(Entry::Debit(30)).expect("no underflow");
+///
+/// assert_eq!(ledger.balance().into_inner(), 120);
+/// assert_eq!(ledger.rejected(), &[Entry::Debit(200)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ledger<T> {
+    balance: Checked<T, WithoutDeref>,
+    rejected: Option<Vec<Entry<T>>>,
+}
+
+impl<T> Ledger<T> {
+    /// Create a ledger with an `initial` balance
+    pub fn new(initial: Checked<T, WithoutDeref>) -> Self {
+        Self {
+            balance: initial,
+            rejected: None,
+        }
+    }
+
+    /// Create a ledger with an `initial` balance, that records rejected entries
+    pub fn with_rejected_log(initial: Checked<T, WithoutDeref>) -> Self {
+        Self {
+            balance: initial,
+            rejected: Some(Vec::new()),
+        }
+    }
+
+    /// Current balance
+    pub fn balance(&self) -> &Checked<T, WithoutDeref> {
+        &self.balance
+    }
+
+    /// Entries rejected so far
+    ///
+    /// Always empty, unless created with [`Ledger::with_rejected_log()`].
+    pub fn rejected(&self) -> &[Entry<T>] {
+        self.rejected.as_deref().unwrap_or(&[])
+    }
+
+    /// Consume the ledger, returning the final balance
+    pub fn into_balance(self) -> Checked<T, WithoutDeref> {
+        self.balance
+    }
+}
+
+impl<T> Ledger<T>
+where
+    T: Clone + CheckedAdd<Output = T> + CheckedSub<Output = T>,
+{
+    /// Apply an entry to the balance
+    ///
+    /// On overflow or underflow, the balance is left unchanged.
+    pub fn apply(&mut self, entry: Entry<T>) -> Result<(), RejectedEntry<T>> {
+        let balance = self.balance.v.clone();
+        let res = match &entry {
+            Entry::Credit(amount) => balance.checked_add_bounded(amount.clone()),
+            Entry::Debit(amount) => balance.checked_sub_bounded(amount.clone()),
+        };
+        match res {
+            Ok(v) => {
+                self.balance.v = v;
+                Ok(())
+            }
+            Err(bound) => {
+                if let Some(rejected) = self.rejected.as_mut() {
+                    rejected.push(entry.clone());
+                }
+                Err(RejectedEntry(entry, bound))
+            }
+        }
+    }
+
+    /// Increase the balance by `amount`
+    pub fn credit(&mut self, amount: T) -> Result<(), RejectedEntry<T>> {
+        self.apply(Entry::Credit(amount))
+    }
+
+    /// Decrease the balance by `amount`
+    pub fn debit(&mut self, amount: T) -> Result<(), RejectedEntry<T>> {
+        self.apply(Entry::Debit(amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_without_changing_balance() {
+        let mut ledger = Ledger::new(Checked::new_without_deref(250u8));
+        assert_eq!(
+            ledger.credit(10),
+            Err(RejectedEntry(Entry::Credit(10), Some(OverflowBound::Max)))
+        );
+        assert_eq!(ledger.balance().into_inner(), 250);
+        assert!(ledger.rejected().is_empty());
+
+        ledger.debit(250).expect("no underflow");
+        let err = ledger.debit(1).unwrap_err();
+        assert_eq!(err, RejectedEntry(Entry::Debit(1), Some(OverflowBound::Min)));
+        assert_eq!(err.to_string(), "entry rejected due to underflow: Debit(1)");
+        assert_eq!(ledger.into_balance().into_inner(), 0);
+    }
+}
@@ -1,14 +1,257 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::cmp::{Eq, PartialEq};
-use std::marker::PhantomData;
-use std::ops::{Add, Deref, Div, Mul, Sub};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::borrow::Borrow;
+use core::cmp::{Eq, Ordering, PartialEq, PartialOrd};
+use core::marker::PhantomData;
+use core::convert::TryInto;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Deref, Div, DivAssign, Mul, MulAssign, Neg, Not,
+    RangeBounds, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
 
 /// Base traits & ops for inner types wrapped by `Checked` and `Unchecked`
 // Why weren't these made into stdlib traits from the ground up?! :(
 mod base_checked_ops;
 pub use base_checked_ops::*;
 
+/// Type aliases for common instantiations of [`Checked`]
+pub mod aliases;
+
+/// Expression trees explaining where overflow happened
+#[cfg(feature = "alloc")]
+mod expr;
+#[cfg(feature = "alloc")]
+pub use expr::*;
+
+/// Parsing integers into [`Checked`] values
+mod parse;
+pub use parse::*;
+
+/// Accumulating credits and debits into a balance
+#[cfg(feature = "alloc")]
+mod ledger;
+#[cfg(feature = "alloc")]
+pub use ledger::*;
+
+/// Accumulating per-key totals
+#[cfg(feature = "std")]
+mod counter;
+#[cfg(feature = "std")]
+pub use counter::*;
+
+/// Tracking reservations against a fixed capacity
+mod quota;
+pub use quota::*;
+
+/// Custom steps in [`Unchecked`] chains
+mod combinators;
+
+/// Non-consuming overflow predicates
+mod headroom;
+
+/// Formatting [`Checked`] values
+mod format;
+
+/// Double-width integers for multiplication without a wider primitive
+mod double_wide;
+pub use double_wide::*;
+
+/// Carry and borrow propagation for multi-word arithmetic
+mod carry;
+
+/// Rounding to power-of-two alignments
+mod align;
+
+/// Checking batches of values at once
+#[cfg(feature = "alloc")]
+mod collect;
+#[cfg(feature = "alloc")]
+pub use collect::*;
+
+/// Summing into a wider type
+mod sum;
+pub use sum::*;
+
+/// Compensated summation of floats
+mod kahan;
+pub use kahan::*;
+
+/// Midpoints and averages without overflowing intermediates
+mod midpoint;
+
+/// Exact comparison of ratios
+mod ratio;
+pub use ratio::*;
+
+/// Conversions between SI-prefixed units
+mod si;
+pub use si::*;
+
+/// Linear scaling with widened intermediates
+mod scale;
+
+/// Buffer sizes of common encodings
+mod encoded_len;
+
+/// Splitting lengths into chunks
+mod chunks;
+
+/// Extracting and replacing ranges of bits
+mod bits;
+
+/// Converting [`Checked`] durations to and from integer units
+mod duration;
+
+/// Offsetting `Instant` and `SystemTime` by [`Checked`] durations
+#[cfg(feature = "std")]
+mod time;
+#[cfg(feature = "std")]
+pub use time::*;
+
+/// Conversions to and from `Wrapping` and `Saturating`
+mod wrappers;
+
+/// Arithmetic usable in `const` contexts
+mod const_ops;
+
+/// Operations involving `NonZero*` types
+mod nonzero;
+
+/// Integration with `secrecy` for sensitive values
+#[cfg(feature = "secrecy")]
+mod secret;
+#[cfg(feature = "secrecy")]
+pub use secret::*;
+
+/// Integration with `speedy` serialization
+#[cfg(feature = "speedy")]
+mod speedy;
+
+/// Integration with `serde` serialization
+#[cfg(feature = "serde")]
+mod serde;
+
+/// Integration with `num-traits`, for its types as inner types and generic code
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "num-traits")]
+pub use self::num_traits::*;
+
+/// `num-bigint` types as inner types
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+
+/// `primitive-types` 256 and 512 bit integers as inner types
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+
+/// `rust_decimal` `Decimal` as inner type
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+
+/// `fixed` fixed-point numbers as inner types
+#[cfg(feature = "fixed")]
+mod fixed;
+
+/// `half` precision floats as inner types
+#[cfg(feature = "half")]
+mod half;
+
+/// `chrono` durations and timestamps as inner types
+#[cfg(feature = "chrono")]
+mod chrono;
+
+/// `time` durations and timestamps as inner types
+#[cfg(feature = "time")]
+mod time_crate;
+
+/// Integration with `bytemuck` for casting buffers of integers
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+/// Integration with `bincode` serialization
+#[cfg(feature = "bincode")]
+mod bincode;
+
+/// Integration with `borsh` serialization
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// Integration with `rkyv` zero-copy archives
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+/// Integration with `validator` for input validation
+#[cfg(feature = "validator")]
+mod validator;
+
+/// Integration with `utoipa` for OpenAPI schemas
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+/// Reading and writing [`Checked`] values from byte streams
+#[cfg(feature = "std-io")]
+mod io;
+#[cfg(feature = "std-io")]
+pub use io::*;
+
+/// Integration with `arbitrary` for fuzzing
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+/// `proptest` strategies generating [`Checked`] and [`Unchecked`] values
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+/// Integration with `quickcheck` for property testing
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+
+/// Integration with `rand` for sampling random values
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand")]
+pub use self::rand::*;
+
+/// Making operations fail on demand, to test overflow handling
+#[cfg(feature = "fault-injection")]
+mod fault;
+#[cfg(feature = "fault-injection")]
+pub use fault::*;
+
+#[cfg(not(feature = "fault-injection"))]
+#[inline(always)]
+fn inject<T>(res: Option<T>) -> Option<T> {
+    res
+}
+
+/// Panicking on overflowed values dropped without being checked
+#[cfg(feature = "drop-guard")]
+mod guard;
+#[cfg(feature = "drop-guard")]
+pub use guard::*;
+
+/// Details of the operation that overflowed
+#[cfg(feature = "rich-errors")]
+mod rich_error;
+#[cfg(feature = "rich-errors")]
+pub use rich_error::*;
+
+/// `?` operator on [`Unchecked`] values
+#[cfg(feature = "nightly")]
+mod try_op;
+
+/// Comparisons and arithmetic between [`Checked`] values of different integer types
+mod cross_width;
+pub use cross_width::*;
+
 /// Marker for [`Checked`] that can be converted to the inner type semi-automatically.
 #[derive(Debug)]
 pub struct WithDeref;
@@ -26,6 +269,10 @@ pub struct WithoutDeref;
 /// Only when the final result is to be used, it's neccessary to call [`Unchecked::check()`]
 /// to convert back to [`Checked`] value.
 ///
+/// The right hand side of an operator is of the same type as `T`, so integer literals infer
+/// to it. Other types convertible with `TryInto` are accepted by [`Checked::try_add()`] and
+/// friends, treating conversion failure like an overflow.
+///
 /// `T` is the inner type (`u8`, `i16`, etc.) wrapped by this type.
 ///
 /// `D` is a marker controlling automatic conversion to inner type. It defaults to [`WithDeref`]
@@ -34,6 +281,22 @@ pub struct WithoutDeref;
 /// [`WithoutDeref`] can be used, which will require calling
 /// an explicit conversion function to convert to the inner type.
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize),
+    rkyv(archived = ArchivedChecked)
+)]
+#[repr(transparent)]
 pub struct Checked<T, D = WithDeref> {
     v: T,
     _deref: PhantomData<D>,
@@ -60,8 +323,16 @@ impl<T, D> From<T> for Checked<T, D> {
 }
 
 impl<T, D> Checked<T, D> {
-    pub fn into_inner(self) -> T {
-        self.v
+    pub const fn into_inner(self) -> T {
+        // Moving the field out directly would need `self` dropped in `const`
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `ManuallyDrop` and `Checked` are both `repr(transparent)`, so this
+        // points to `v`, which is read only once and never dropped in place
+        unsafe { core::ptr::read(&this as *const core::mem::ManuallyDrop<Self> as *const T) }
+    }
+
+    pub const fn as_inner(&self) -> &T {
+        &self.v
     }
 }
 
@@ -75,15 +346,49 @@ where
 
 }
 
+impl<T, D> Checked<T, D>
+where
+    T: Bounded,
+{
+    pub const MIN: Self = Self {
+        v: T::MIN,
+        _deref: PhantomData,
+    };
+    pub const MAX: Self = Self {
+        v: T::MAX,
+        _deref: PhantomData,
+    };
+}
+
+impl<T, D> Checked<T, D>
+where
+    T: Zero,
+{
+    pub const ZERO: Self = Self {
+        v: T::ZERO,
+        _deref: PhantomData,
+    };
+}
+
+impl<T, D> Checked<T, D>
+where
+    T: One,
+{
+    pub const ONE: Self = Self {
+        v: T::ONE,
+        _deref: PhantomData,
+    };
+}
+
 impl<T> Checked<T, WithDeref> {
-    pub fn new_with_deref(v: T) -> Checked<T, WithDeref> {
+    pub const fn new_with_deref(v: T) -> Checked<T, WithDeref> {
         Self {
             v,
             _deref: PhantomData,
         }
     }
 
-    pub fn new(v: T) -> Self {
+    pub const fn new(v: T) -> Self {
         Self {
             v,
             _deref: PhantomData,
@@ -92,7 +397,7 @@ impl<T> Checked<T, WithDeref> {
 }
 
 impl<T> Checked<T, WithoutDeref> {
-    pub fn new_without_deref(v: T) -> Checked<T, WithoutDeref> {
+    pub const fn new_without_deref(v: T) -> Checked<T, WithoutDeref> {
         Self {
             v,
             _deref: PhantomData,
@@ -101,6 +406,86 @@ impl<T> Checked<T, WithoutDeref> {
 
 }
 
+impl<T, D> Checked<T, D> {
+    /// Start a chain of operations that keeps the wrapped result on overflow.
+    ///
+    /// See [`Overflowing`].
+    pub fn overflowing(self) -> Overflowing<T, D> {
+        Overflowing {
+            v: self.v,
+            overflow: false,
+            _deref: self._deref,
+        }
+    }
+}
+
+impl<T, D> Checked<T, D> {
+    /// Convert to another integer type, failing if the value doesn't fit.
+    pub fn narrow<U>(self) -> Unchecked<U, D>
+    where
+        T: Narrow<U>,
+    {
+        Unchecked::from(self.v.checked_narrow())
+    }
+
+    /// Convert to another integer type, clamping to its bounds if the value doesn't fit.
+    pub fn saturating_narrow<U>(self) -> Checked<U, D>
+    where
+        T: Narrow<U>,
+    {
+        Checked {
+            v: self.v.saturating_narrow(),
+            _deref: self._deref,
+        }
+    }
+
+    /// Convert to another integer type, truncating with two's-complement wrapping
+    /// if the value doesn't fit (like an `as` cast).
+    pub fn wrapping_narrow<U>(self) -> Checked<U, D>
+    where
+        T: Narrow<U>,
+    {
+        Checked {
+            v: self.v.wrapping_narrow(),
+            _deref: self._deref,
+        }
+    }
+}
+
+macro_rules! impl_cast_sign {
+    ($($u:ty, $i:ty);*) => {
+        $(
+            impl<D> Checked<$u, D> {
+                /// Reinterpret the bits as the signed integer of the same width.
+                ///
+                /// Unlike [`Checked::narrow()`], the value is not preserved
+                /// for values above the signed maximum.
+                pub fn cast_signed(self) -> Checked<$i, D> {
+                    Checked {
+                        v: self.v as $i,
+                        _deref: self._deref,
+                    }
+                }
+            }
+
+            impl<D> Checked<$i, D> {
+                /// Reinterpret the bits as the unsigned integer of the same width.
+                ///
+                /// Unlike [`Checked::narrow()`], the value is not preserved
+                /// for negative values.
+                pub fn cast_unsigned(self) -> Checked<$u, D> {
+                    Checked {
+                        v: self.v as $u,
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cast_sign!(usize, isize; u8, i8; u16, i16; u32, i32; u64, i64; u128, i128);
+
 impl<T> Deref for Checked<T, WithDeref> {
     type Target = T;
 
@@ -109,6 +494,19 @@ impl<T> Deref for Checked<T, WithDeref> {
     }
 }
 
+// Like `Deref`, these would make the inner value reachable without naming it
+impl<T> AsRef<T> for Checked<T, WithDeref> {
+    fn as_ref(&self) -> &T {
+        &self.v
+    }
+}
+
+impl<T> Borrow<T> for Checked<T, WithDeref> {
+    fn borrow(&self) -> &T {
+        &self.v
+    }
+}
+
 
 /// Intermediate result of artimetic operations on [`Checked`] value that might contain overflow
 ///
@@ -116,9 +514,15 @@ impl<T> Deref for Checked<T, WithDeref> {
 /// or a marker that overflow was detected and any subsequent
 /// artimetic operations will keep resulting
 /// in overflow, similiarly to how NaN behaves.
+///
+/// Where known, it also remembers which bound of `T` the overflowed
+/// result went past, for [`Unchecked::saturate()`].
 #[derive(Debug)]
+#[must_use = "overflow is only detected when the value is checked"]
 pub struct Unchecked<T, D = WithoutDeref> {
     v: Option<T>,
+    // Meaningful only when `v` is `None`
+    overflow: Overflow,
     _deref: PhantomData<D>,
 }
 
@@ -129,6 +533,7 @@ where
     fn clone(&self) -> Self {
         Self {
             v: self.v.clone(),
+            overflow: self.overflow,
             _deref: self._deref,
         }
     }
@@ -136,31 +541,310 @@ where
 
 impl<T, D> Copy for Unchecked<T, D> where T: Copy {}
 
+impl<T, D> From<T> for Unchecked<T, D> {
+    fn from(v: T) -> Self {
+        Self {
+            v: Some(v),
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+/// `None` becomes an overflow, e.g. a result of a `checked_*` method of the inner type
+impl<T, D> From<Option<T>> for Unchecked<T, D> {
+    fn from(v: Option<T>) -> Self {
+        Self {
+            v,
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+/// Overflow becomes `None`
+impl<T, D> From<Unchecked<T, D>> for Option<T> {
+    fn from(unchecked: Unchecked<T, D>) -> Self {
+        unchecked.into_parts().0
+    }
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Take the value and what is known about the overflow out
+    #[inline(always)]
+    fn into_parts(self) -> (Option<T>, Overflow) {
+        (self.v, self.overflow)
+    }
+
+    #[inline(always)]
+    fn overflowed(overflow: Overflow) -> Self {
+        Unchecked {
+            v: None,
+            overflow,
+            _deref: PhantomData,
+        }
+    }
+
+    /// Result of an operation reporting the bound crossed on overflow
+    #[inline(always)]
+    fn from_bounded(res: Result<T, Option<OverflowBound>>) -> Self {
+        match res {
+            Ok(v) => Unchecked::from(inject(Some(v))),
+            Err(bound) => Unchecked::overflowed(Overflow::past(bound)),
+        }
+    }
+
+    /// Result of an operation describing the overflow
+    #[cfg(feature = "rich-errors")]
+    #[inline(always)]
+    fn from_detailed(res: Result<T, Overflow>) -> Self {
+        match res {
+            Ok(v) => Unchecked::from(inject(Some(v))),
+            Err(overflow) => Unchecked::overflowed(overflow),
+        }
+    }
+
+    /// Continue with the value, or carry the overflow over, updating its bound with `propagate`
+    #[inline(always)]
+    fn then<U, D2, F>(
+        self,
+        propagate: fn(Option<OverflowBound>) -> Option<OverflowBound>,
+        f: F,
+    ) -> Unchecked<U, D2>
+    where
+        F: FnOnce(T) -> Unchecked<U, D2>,
+    {
+        match self.into_parts() {
+            (Some(v), _) => f(v),
+            (None, overflow) => Unchecked::overflowed(overflow.map_bound(propagate)),
+        }
+    }
+}
+
+// What happens to the bound of an already overflowed value in further operations
+
+// Adding or subtracting any value keeps it past the same bound
+#[inline(always)]
+fn keep_bound(bound: Option<OverflowBound>) -> Option<OverflowBound> {
+    bound
+}
+
+// Negating moves it past the opposite one
+#[inline(always)]
+fn reverse_bound(bound: Option<OverflowBound>) -> Option<OverflowBound> {
+    bound.map(|bound| match bound {
+        OverflowBound::Min => OverflowBound::Max,
+        OverflowBound::Max => OverflowBound::Min,
+    })
+}
+
+// After multiplication or division by an unknown value, it could be anywhere
+#[inline(always)]
+fn forget_bound(_: Option<OverflowBound>) -> Option<OverflowBound> {
+    None
+}
+
+
 impl<T, D> Unchecked<T, D> {
     /// Convert back to [`Checked`].
     ///
     /// Returns `None` if inner value denotes overflow.
+    #[must_use]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
     pub fn check(self) -> Option<Checked<T, D>> {
-        self.v.map(|v| Checked {
+        self.into_parts().0.map(|v| Checked {
             v,
             _deref: PhantomData,
         })
     }
+
+    /// Convert back to [`Checked`], validating that the value is within `range`.
+    ///
+    /// The returned error distinguishes overflow from a value outside of the range.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn check_in_range<R>(self, range: R) -> Result<Checked<T, D>, CheckInRangeError>
+    where
+        T: PartialOrd,
+        R: RangeBounds<T>,
+    {
+        match self.into_parts().0 {
+            None => Err(CheckInRangeError::Overflow),
+            Some(v) if range.contains(&v) => Ok(Checked {
+                v,
+                _deref: PhantomData,
+            }),
+            Some(_) => Err(CheckInRangeError::OutOfRange),
+        }
+    }
+
+    /// Convert back to [`Checked`], returning an error if inner value denotes overflow.
+    ///
+    /// Same as [`Unchecked::check()`], but composes with `?` in functions returning `Result`.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn check_or_err(self) -> Result<Checked<T, D>, OverflowError> {
+        self.check().ok_or(OverflowError)
+    }
+
+    /// Convert back to [`Checked`], panicking with `msg` on overflow.
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> Checked<T, D> {
+        self.check().expect(msg)
+    }
+
+    /// Convert back to [`Checked`], panicking on overflow.
+    #[track_caller]
+    pub fn unwrap(self) -> Checked<T, D> {
+        self.expect("arithmetic overflow")
+    }
+
+    /// Convert back to [`Checked`], using `default` on overflow.
+    pub fn unwrap_or(self, default: T) -> Checked<T, D> {
+        Checked::from(self.into_parts().0.unwrap_or(default))
+    }
+
+    /// Convert back to [`Checked`], using the value returned by `f` on overflow.
+    pub fn unwrap_or_else<F>(self, f: F) -> Checked<T, D>
+    where
+        F: FnOnce() -> T,
+    {
+        Checked::from(self.into_parts().0.unwrap_or_else(f))
+    }
+
+    /// Convert back to [`Checked`], using the default value of `T` on overflow.
+    pub fn unwrap_or_default(self) -> Checked<T, D>
+    where
+        T: Default,
+    {
+        Checked::from(self.into_parts().0.unwrap_or_default())
+    }
+
+    /// Convert back to [`Checked`], returning `err` on overflow.
+    pub fn ok_or<E>(self, err: E) -> Result<Checked<T, D>, E> {
+        self.check().ok_or(err)
+    }
+
+    /// Convert back to [`Checked`], clamping an overflowed value to the bound it went past.
+    ///
+    /// The bound is known after overflow in addition, subtraction, multiplication,
+    /// division, negation, `abs()` and `pow()` of primitive integers, and stays known
+    /// through further additions, subtractions and negations. Returns `None` if it isn't,
+    /// e.g. after division by zero or multiplying an already overflowed value.
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// let price = Checked::new(200u8) * 2 - 100;
+    /// assert_eq!(price.saturate().map(|p| *p), Some(u8::MAX));
+    ///
+    /// let balance = -(Checked::new(i8::MIN) - 1);
+    /// assert_eq!(balance.saturate().map(|b| *b), Some(i8::MAX));
+    ///
+    /// assert!((Checked::new(200u8) / 0).saturate().is_none());
+    /// ```
+    pub fn saturate(self) -> Option<Checked<T, D>>
+    where
+        T: Bounded,
+    {
+        match self.into_parts() {
+            (Some(v), _) => Some(Checked::from(v)),
+            (None, overflow) => match overflow.bound {
+                Some(OverflowBound::Min) => Some(Checked::MIN),
+                Some(OverflowBound::Max) => Some(Checked::MAX),
+                None => None,
+            },
+        }
+    }
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Convert to another integer type, failing if the value doesn't fit.
+    pub fn narrow<U>(self) -> Unchecked<U, D>
+    where
+        T: Narrow<U>,
+    {
+        // Narrowing doesn't bring a value back within bounds
+        self.then(keep_bound, |v| Unchecked::from(v.checked_narrow()))
+    }
+}
+
+/// Error returned by [`Unchecked::check_or_err()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("arithmetic overflow")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
+
+impl From<OverflowError> for CheckInRangeError {
+    fn from(_: OverflowError) -> Self {
+        CheckInRangeError::Overflow
+    }
+}
+
+/// Error returned by [`Unchecked::check_in_range()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckInRangeError {
+    /// An overflow occurred during calculation
+    Overflow,
+    /// The calculated value is outside of the expected range
+    OutOfRange,
+}
+
+impl fmt::Display for CheckInRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CheckInRangeError::Overflow => "arithmetic overflow",
+            CheckInRangeError::OutOfRange => "value out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CheckInRangeError {}
+
+// Apply an operation to an inner value. Given a `_detailed` method, the
+// overflow is described with `rich-errors`; given a `bounded` one, the bound
+// crossed on overflow is tracked.
+macro_rules! apply_op {
+    ($v:expr $(, $rhs:expr)?; $checked_method:ident) => {
+        Unchecked::from(inject($v.$checked_method($($rhs)?)))
+    };
+    ($v:expr $(, $rhs:expr)?; bounded $bounded_method:ident) => {
+        Unchecked::from_bounded($v.$bounded_method($($rhs)?))
+    };
+    ($v:expr $(, $rhs:expr)?; $checked_method:ident, $detailed_method:ident) => {{
+        #[cfg(feature = "rich-errors")]
+        let res = Unchecked::from_detailed($v.$detailed_method($($rhs)?));
+        #[cfg(not(feature = "rich-errors"))]
+        let res = Unchecked::from(inject($v.$checked_method($($rhs)?)));
+        res
+    }};
+    ($v:expr $(, $rhs:expr)?; bounded $bounded_method:ident, $detailed_method:ident) => {{
+        #[cfg(feature = "rich-errors")]
+        let res = Unchecked::from_detailed($v.$detailed_method($($rhs)?));
+        #[cfg(not(feature = "rich-errors"))]
+        let res = Unchecked::from_bounded($v.$bounded_method($($rhs)?));
+        res
+    }};
 }
 
+// An already overflowed value stays overflowed, with its bound updated by `$propagate`
 macro_rules! impl_op {
-    ($op:tt,$checked_op:tt,$method:ident,$checked_method:ident) => {
+    ($op:tt, $checked_op:tt, $method:ident, $propagate:ident; $($apply:tt)*) => {
         impl<T, D, Rhs> $op<Rhs> for Checked<T, D>
         where
             T: $checked_op<Rhs>,
         {
             type Output = Unchecked<<T as $checked_op<Rhs>>::Output, D>;
 
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
             fn $method(self, rhs: Rhs) -> Self::Output {
-                Unchecked {
-                    v: self.v.$checked_method(rhs),
-                    _deref: self._deref,
-                }
+                apply_op!(self.v, rhs; $($apply)*)
             }
         }
 
@@ -170,44 +854,544 @@ macro_rules! impl_op {
         {
             type Output = Unchecked<<T as $checked_op<Rhs>>::Output, D>;
 
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
             fn $method(self, rhs: Rhs) -> Self::Output {
-                Unchecked {
-                    v: self.v.and_then(|v| v.$checked_method(rhs)),
-                    _deref: self._deref,
+                self.then($propagate, |v| apply_op!(v, rhs; $($apply)*))
+            }
+        }
+    };
+}
+
+impl_op!(Add, CheckedAdd, add, keep_bound; bounded checked_add_bounded, checked_add_detailed);
+impl_op!(Sub, CheckedSub, sub, keep_bound; bounded checked_sub_bounded, checked_sub_detailed);
+impl_op!(Mul, CheckedMul, mul, forget_bound; bounded checked_mul_bounded, checked_mul_detailed);
+impl_op!(Div, CheckedDiv, div, forget_bound; bounded checked_div_bounded, checked_div_detailed);
+impl_op!(Rem, CheckedRem, rem, forget_bound; checked_rem, checked_rem_detailed);
+impl_op!(Shl, CheckedShl, shl, forget_bound; checked_shl);
+impl_op!(Shr, CheckedShr, shr, forget_bound; checked_shr);
+
+// Accumulating in place, e.g. in a loop
+macro_rules! impl_op_assign {
+    ($op:tt, $checked_op:tt, $method:ident, $propagate:ident; $($apply:tt)*) => {
+        impl<T, D, Rhs> $op<Rhs> for Unchecked<T, D>
+        where
+            T: $checked_op<Rhs, Output = T>,
+        {
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $method(&mut self, rhs: Rhs) {
+                let this = Unchecked {
+                    v: self.v.take(),
+                    overflow: self.overflow,
+                    _deref: PhantomData::<D>,
+                };
+                *self = this.then($propagate, |v| apply_op!(v, rhs; $($apply)*));
+            }
+        }
+    };
+}
+
+impl_op_assign!(AddAssign, CheckedAdd, add_assign, keep_bound; bounded checked_add_bounded, checked_add_detailed);
+impl_op_assign!(SubAssign, CheckedSub, sub_assign, keep_bound; bounded checked_sub_bounded, checked_sub_detailed);
+impl_op_assign!(MulAssign, CheckedMul, mul_assign, forget_bound; bounded checked_mul_bounded, checked_mul_detailed);
+impl_op_assign!(DivAssign, CheckedDiv, div_assign, forget_bound; bounded checked_div_bounded, checked_div_detailed);
+impl_op_assign!(RemAssign, CheckedRem, rem_assign, forget_bound; checked_rem, checked_rem_detailed);
+
+// Right hand side of another type, with conversion failure treated like an overflow
+macro_rules! impl_try_op {
+    ($(#[$attr:meta])* $method:ident, $checked_op:tt, $propagate:ident; $($apply:tt)*) => {
+        impl<T, D> Checked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method<Rhs>(self, rhs: Rhs) -> Unchecked<<T as $checked_op>::Output, D>
+            where
+                T: $checked_op,
+                Rhs: TryInto<T>,
+            {
+                match rhs.try_into() {
+                    Ok(rhs) => apply_op!(self.v, rhs; $($apply)*),
+                    Err(_) => Unchecked::overflowed(Overflow::default()),
                 }
             }
         }
+
+        impl<T, D> Unchecked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method<Rhs>(self, rhs: Rhs) -> Unchecked<<T as $checked_op>::Output, D>
+            where
+                T: $checked_op,
+                Rhs: TryInto<T>,
+            {
+                self.then($propagate, |v| match rhs.try_into() {
+                    Ok(rhs) => apply_op!(v, rhs; $($apply)*),
+                    Err(_) => Unchecked::overflowed(Overflow::default()),
+                })
+            }
+        }
     };
 }
 
-impl_op!(Add, CheckedAdd, add, checked_add);
-impl_op!(Sub, CheckedSub, sub, checked_sub);
-impl_op!(Mul, CheckedMul, mul, checked_mul);
-impl_op!(Div, CheckedDiv, div, checked_div);
+impl_try_op!(
+    /// Add a right hand side of another type, treating failed conversion like an overflow.
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// assert_eq!(*Checked::new(10u8).try_add(200i32).check().unwrap(), 210);
+    /// assert!(Checked::new(10u8).try_add(300i32).check().is_none());
+    /// ```
+    try_add,
+    CheckedAdd,
+    keep_bound;
+    bounded checked_add_bounded, checked_add_detailed
+);
+
+impl_try_op!(
+    /// Subtract a right hand side of another type, treating failed conversion like an overflow.
+    try_sub,
+    CheckedSub,
+    keep_bound;
+    bounded checked_sub_bounded, checked_sub_detailed
+);
 
-impl<T, D1, D2> PartialEq<Checked<T, D1>> for Checked<T, D2>
+impl_try_op!(
+    /// Multiply by a right hand side of another type, treating failed conversion like an overflow.
+    try_mul,
+    CheckedMul,
+    forget_bound;
+    bounded checked_mul_bounded, checked_mul_detailed
+);
+
+impl_try_op!(
+    /// Divide by a right hand side of another type, treating failed conversion like an overflow.
+    try_div,
+    CheckedDiv,
+    forget_bound;
+    bounded checked_div_bounded, checked_div_detailed
+);
+
+impl_try_op!(
+    /// Remainder by a right hand side of another type, treating failed conversion like an overflow.
+    try_rem,
+    CheckedRem,
+    forget_bound;
+    checked_rem, checked_rem_detailed
+);
+
+impl<T, D> Neg for Checked<T, D>
 where
-    T: PartialEq<T>,
+    T: CheckedNeg,
 {
-    fn eq(&self, other: &Checked<T, D1>) -> bool {
-        self.v.eq(&other.v)
+    type Output = Unchecked<T, D>;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn neg(self) -> Self::Output {
+        Unchecked::from_bounded(self.v.checked_neg_bounded())
     }
 }
-impl<T, D1> Eq for Checked<T, D1> where T: PartialEq<T> {}
 
+impl<T, D> Neg for Unchecked<T, D>
+where
+    T: CheckedNeg,
+{
+    type Output = Unchecked<T, D>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn neg(self) -> Self::Output {
+        self.then(reverse_bound, |v| Unchecked::from_bounded(v.checked_neg_bounded()))
+    }
+}
 
-    #[test]
-    fn it_works() {
-        assert_eq!(
-            *{ Checked::new_with_deref(1u8) * 12 - 2 }
-                .check()
-                .expect("no oveflow"),
-            10
-        );
+// Bitwise operations can't overflow, so they stay `Checked`
+macro_rules! impl_bit_op {
+    ($op:tt, $method:ident; $($t:ty),*) => {
+        $(
+            impl<D> $op<$t> for Checked<$t, D> {
+                type Output = Checked<$t, D>;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $method(self, rhs: $t) -> Self::Output {
+                    Checked {
+                        v: self.v.$method(rhs),
+                        _deref: self._deref,
+                    }
+                }
+            }
+
+            impl<D, D2> $op<Checked<$t, D2>> for Checked<$t, D> {
+                type Output = Checked<$t, D>;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn $method(self, rhs: Checked<$t, D2>) -> Self::Output {
+                    Checked {
+                        v: self.v.$method(rhs.v),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_op!(BitAnd, bitand; usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+impl_bit_op!(BitOr, bitor; usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+impl_bit_op!(BitXor, bitxor; usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+impl<T, D> Not for Checked<T, D>
+where
+    T: Not<Output = T>,
+{
+    type Output = Checked<T, D>;
+
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    fn not(self) -> Self::Output {
+        Checked {
+            v: !self.v,
+            _deref: self._deref,
+        }
+    }
+}
+
+macro_rules! impl_method {
+    ($(#[$attr:meta])* $method:ident, $checked_op:tt, $($apply:tt)*) => {
+        impl<T, D> Checked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method<Rhs>(self, rhs: Rhs) -> Unchecked<<T as $checked_op<Rhs>>::Output, D>
+            where
+                T: $checked_op<Rhs>,
+            {
+                apply_op!(self.v, rhs; $($apply)*)
+            }
+        }
+
+        impl<T, D> Unchecked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method<Rhs>(self, rhs: Rhs) -> Unchecked<<T as $checked_op<Rhs>>::Output, D>
+            where
+                T: $checked_op<Rhs>,
+            {
+                self.then(forget_bound, |v| apply_op!(v, rhs; $($apply)*))
+            }
+        }
+    };
+}
+
+impl_method!(
+    /// Divide, treating a non-zero remainder (or division by zero) as an overflow.
+    div_exact,
+    CheckedDivExact,
+    checked_div_exact
+);
+
+impl_method!(
+    /// Euclidean division, rounding so that the remainder is never negative.
+    div_euclid,
+    CheckedDivEuclid,
+    checked_div_euclid, checked_div_euclid_detailed
+);
+
+impl_method!(
+    /// Euclidean remainder, always non-negative.
+    rem_euclid,
+    CheckedRemEuclid,
+    checked_rem_euclid, checked_rem_euclid_detailed
+);
+
+impl_method!(
+    /// Absolute difference, in the same type.
+    ///
+    /// Never overflows for unsigned types. For signed types the difference
+    /// may not fit, e.g. between `MIN` and `MAX`.
+    abs_diff,
+    CheckedAbsDiff,
+    checked_abs_diff
+);
+
+impl_method!(
+    /// Raise to the power of `exp`.
+    pow,
+    CheckedPow,
+    bounded checked_pow_bounded
+);
+
+macro_rules! impl_method_1 {
+    ($(#[$attr:meta])* $method:ident, $checked_op:tt, $checked_method:ident) => {
+        impl_method_1!($(#[$attr])* $method, $checked_op, $checked_method -> T);
+    };
+    ($(#[$attr:meta])* $method:ident, $checked_op:tt, $checked_method:ident -> $out:ty) => {
+        impl_method_1!(@impl $(#[$attr])* $method, $checked_op, $out; $checked_method);
+    };
+    ($(#[$attr:meta])* $method:ident, $checked_op:tt, bounded $bounded_method:ident) => {
+        impl_method_1!(@impl $(#[$attr])* $method, $checked_op, T; bounded $bounded_method);
+    };
+    (@impl $(#[$attr:meta])* $method:ident, $checked_op:tt, $out:ty; $($apply:tt)*) => {
+        impl<T, D> Checked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method(self) -> Unchecked<$out, D>
+            where
+                T: $checked_op,
+            {
+                apply_op!(self.v; $($apply)*)
+            }
+        }
+
+        impl<T, D> Unchecked<T, D> {
+            $(#[$attr])*
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            pub fn $method(self) -> Unchecked<$out, D>
+            where
+                T: $checked_op,
+            {
+                self.then(forget_bound, |v| apply_op!(v; $($apply)*))
+            }
+        }
+    };
+}
+
+impl_method_1!(
+    /// Absolute value, overflowing for the minimum value of a signed type.
+    abs,
+    CheckedAbs,
+    bounded checked_abs_bounded
+);
+
+impl_method!(
+    /// Round up to the nearest multiple of `rhs`, overflowing if `rhs` is zero.
+    next_multiple_of,
+    CheckedNextMultipleOf,
+    checked_next_multiple_of
+);
+
+impl_method!(
+    /// Logarithm in the given `base`, rounded down.
+    ///
+    /// Overflows if the value is not positive, or the base is less than 2.
+    ilog,
+    CheckedIlog,
+    checked_ilog
+);
+
+impl_method_1!(
+    /// Base 2 logarithm, rounded down, overflowing if the value is not positive.
+    ilog2,
+    CheckedIlog2,
+    checked_ilog2 -> u32
+);
+
+impl_method_1!(
+    /// Base 10 logarithm, rounded down, overflowing if the value is not positive.
+    ilog10,
+    CheckedIlog10,
+    checked_ilog10 -> u32
+);
+
+impl_method_1!(
+    /// Square root, rounded down, overflowing if the value is negative.
+    isqrt,
+    CheckedIsqrt,
+    checked_isqrt
+);
+
+impl_method_1!(
+    /// Round up to the nearest power of two.
+    next_power_of_two,
+    CheckedNextPowerOfTwo,
+    checked_next_power_of_two
+);
+
+/// Intermediate result of arithmetic operations on [`Checked`] value
+/// that keeps the two's-complement wrapped value on overflow.
+///
+/// Created with [`Checked::overflowing()`]. Unlike [`Unchecked`], the wrapped
+/// value is always available, similarly to the `overflowing_*` family
+/// of methods on primitive types, while the overflow flag stays set for the rest of the chain.
+///
+/// Only operations that have a well defined wrapped result (`Add`, `Sub`, `Mul`)
+/// are supported.
+#[derive(Debug)]
+pub struct Overflowing<T, D = WithoutDeref> {
+    v: T,
+    overflow: bool,
+    _deref: PhantomData<D>,
+}
+
+impl<T, D> Clone for Overflowing<T, D>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            v: self.v.clone(),
+            overflow: self.overflow,
+            _deref: self._deref,
+        }
+    }
+}
+
+impl<T, D> Copy for Overflowing<T, D> where T: Copy {}
+
+impl<T, D> Overflowing<T, D> {
+    /// Convert back to [`Checked`].
+    ///
+    /// Returns `None` if overflow occurred at any point.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn check(self) -> Option<Checked<T, D>> {
+        if self.overflow {
+            None
+        } else {
+            Some(Checked {
+                v: self.v,
+                _deref: PhantomData,
+            })
+        }
+    }
+
+    /// Return the wrapped value along with a flag whether overflow occurred.
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn check_wrapping(self) -> (T, bool) {
+        (self.v, self.overflow)
+    }
+}
+
+macro_rules! impl_overflowing_op {
+    ($op:tt,$overflowing_op:tt,$method:ident,$overflowing_method:ident) => {
+        impl<T, D, Rhs> $op<Rhs> for Overflowing<T, D>
+        where
+            T: $overflowing_op<Rhs>,
+        {
+            type Output = Overflowing<<T as $overflowing_op<Rhs>>::Output, D>;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn $method(self, rhs: Rhs) -> Self::Output {
+                let (v, overflow) = self.v.$overflowing_method(rhs);
+                Overflowing {
+                    v,
+                    overflow: self.overflow || overflow,
+                    _deref: self._deref,
+                }
+            }
+        }
+    };
+}
+
+impl_overflowing_op!(Add, OverflowingAdd, add, overflowing_add);
+impl_overflowing_op!(Sub, OverflowingSub, sub, overflowing_sub);
+impl_overflowing_op!(Mul, OverflowingMul, mul, overflowing_mul);
+
+impl<T, D1, D2> PartialEq<Checked<T, D1>> for Checked<T, D2>
+where
+    T: PartialEq<T>,
+{
+    fn eq(&self, other: &Checked<T, D1>) -> bool {
+        self.v.eq(&other.v)
+    }
+}
+impl<T, D1> Eq for Checked<T, D1> where T: Eq {}
+
+impl<T, D1, D2> PartialOrd<Checked<T, D1>> for Checked<T, D2>
+where
+    T: PartialOrd<T>,
+{
+    fn partial_cmp(&self, other: &Checked<T, D1>) -> Option<Ordering> {
+        self.v.partial_cmp(&other.v)
+    }
+}
+
+// Formatting looks through to the inner value, flags and all
+macro_rules! impl_fmt {
+    ($($fmt:ident),*) => {
+        $(
+            impl<T, D> fmt::$fmt for Checked<T, D>
+            where
+                T: fmt::$fmt,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$fmt::fmt(&self.v, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(Display, LowerHex, UpperHex, Binary, Octal);
+
+impl<T, D> PartialEq<T> for Checked<T, D>
+where
+    T: PartialEq<T>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.v.eq(other)
+    }
+}
+
+impl<T, D> PartialOrd<T> for Checked<T, D>
+where
+    T: PartialOrd<T>,
+{
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.v.partial_cmp(other)
+    }
+}
+
+// Mirror impls, so comparisons read the same both ways around
+macro_rules! impl_cmp_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl<D> PartialEq<Checked<$t, D>> for $t {
+                fn eq(&self, other: &Checked<$t, D>) -> bool {
+                    self.eq(&other.v)
+                }
+            }
+
+            impl<D> PartialOrd<Checked<$t, D>> for $t {
+                fn partial_cmp(&self, other: &Checked<$t, D>) -> Option<Ordering> {
+                    self.partial_cmp(&other.v)
+                }
+            }
+        )*
+    };
+}
+
+impl_cmp_primitive!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+impl<T, D> Default for Checked<T, D>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            v: T::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+// Only the inner value takes part in `PartialEq`, so it's the only thing hashed
+impl<T, D> Hash for Checked<T, D>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.v.hash(state)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(
+            *{ Checked::new_with_deref(1u8) * 12 - 2 }
+                .check()
+                .expect("no oveflow"),
+            10
+        );
 
         assert_eq!(
             { Checked::new(1u8) * 12 - 2 }.check().expect("no oveflow"),
@@ -217,4 +1401,440 @@ mod tests {
         assert!({ Checked::new(1u8) + u8::MAX }.check().is_none());
         assert!({ Checked::new(255u8) + 5 - 100 }.check().is_none());
     }
+
+    #[test]
+    fn rem() {
+        assert_eq!(*{ Checked::new(10u8) % 3 * 5 }.check().expect("no overflow"), 5);
+        assert!({ Checked::new(10u8) % 0 }.check().is_none());
+        let divisor = -1;
+        assert!({ Checked::new(i8::MIN) % divisor }.check().is_none());
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(*{ -(Checked::new(3i32) * 2) + 1 }.check().expect("no overflow"), -5);
+        assert!({ -Checked::new(i32::MIN) }.check().is_none());
+        assert!({ -Checked::new(1u32) }.check().is_none());
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(*{ Checked::new(-3i8).abs() - 1 }.check().expect("no overflow"), 2);
+        assert_eq!(*{ Checked::new(5i8) * -2 }.abs().check().expect("no overflow"), 10);
+        assert!(Checked::new(i8::MIN).abs().check().is_none());
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(*{ Checked::new(10u64).pow(3) * 7 }.check().expect("no overflow"), 7000);
+        assert!({ Checked::new(10u64).pow(20) * 1 }.check().is_none());
+        assert!({ Checked::new(2i8) * 2 }.pow(4).check().is_none());
+    }
+
+    #[test]
+    fn shifts() {
+        assert_eq!(*{ (Checked::new(1u32) << 31) >> 30u8 }.check().expect("no overflow"), 2);
+        assert!({ Checked::new(1u32) << 32 }.check().is_none());
+        assert!({ Checked::new(1u32) >> -1 }.check().is_none());
+    }
+
+    #[test]
+    fn bit_ops() {
+        let flags: Checked<u8> = (Checked::new(0b1100u8) | 0b0011) & Checked::new(0b1010u8);
+        assert_eq!(*flags, 0b1010);
+        assert_eq!(*(!flags ^ 0xff), 0b1010);
+    }
+
+    #[test]
+    fn euclid() {
+        assert_eq!(*Checked::new(-7i32).div_euclid(2).check().expect("no overflow"), -4);
+        assert_eq!(*{ Checked::new(-7i32) + 0 }.rem_euclid(2).check().expect("no overflow"), 1);
+        assert!(Checked::new(i32::MIN).div_euclid(-1).check().is_none());
+        assert!(Checked::new(1u8).rem_euclid(0).check().is_none());
+    }
+
+    #[test]
+    fn abs_diff() {
+        assert_eq!(*Checked::new(3u8).abs_diff(250).check().expect("no overflow"), 247);
+        assert_eq!(*Checked::new(-100i8).abs_diff(27).check().expect("no overflow"), 127);
+        assert!(Checked::new(i8::MIN).abs_diff(0).check().is_none());
+        assert!({ Checked::new(1u8) - 2 }.abs_diff(0).check().is_none());
+    }
+
+    #[test]
+    fn next_multiple_of() {
+        assert_eq!(*Checked::new(13u32).next_multiple_of(8).check().expect("no overflow"), 16);
+        assert!(Checked::new(13u32).next_multiple_of(0).check().is_none());
+        assert!(Checked::new(250u8).next_multiple_of(8).check().is_none());
+        assert_eq!(*{ Checked::new(65u8) * 1 }.next_power_of_two().check().expect("no overflow"), 128);
+        assert!(Checked::new(129u8).next_power_of_two().check().is_none());
+    }
+
+    #[test]
+    fn ilog() {
+        assert_eq!(*Checked::new(1000u32).ilog10().check().expect("no overflow"), 3);
+        assert_eq!(*Checked::new(1023i16).ilog2().check().expect("no overflow"), 9);
+        assert_eq!(*Checked::new(80u64).ilog(3).check().expect("no overflow"), 3);
+        assert!(Checked::new(0u64).ilog2().check().is_none());
+        assert!(Checked::new(-8i64).ilog10().check().is_none());
+        assert!(Checked::new(8u64).ilog(1).check().is_none());
+        assert!({ Checked::new(0u8) - 1 }.ilog2().check().is_none());
+    }
+
+    #[test]
+    fn isqrt() {
+        assert_eq!(*Checked::new(u64::MAX).isqrt().check().expect("no overflow"), u32::MAX as u64);
+        assert_eq!(*{ Checked::new(3i32) * 3 + 16 }.isqrt().check().expect("no overflow"), 5);
+        assert!(Checked::new(-1i32).isqrt().check().is_none());
+    }
+
+    #[test]
+    fn check_or_err() {
+        fn total(a: u8, b: u8) -> Result<u8, Box<dyn std::error::Error>> {
+            Ok(*(Checked::new(a) + b).check_or_err()?)
+        }
+
+        assert_eq!(total(1, 2).unwrap(), 3);
+        assert_eq!(total(255, 1).unwrap_err().to_string(), "arithmetic overflow");
+        assert_eq!({ Checked::new(0u8) - 1 }.check_or_err(), Err(OverflowError));
+    }
+
+    #[test]
+    fn expect_unwrap() {
+        assert_eq!(*(Checked::new(2u8) * 3).unwrap(), 6);
+        assert_eq!(*(Checked::new(2u8) - 2).expect("non-negative"), 0);
+
+        let err = std::panic::catch_unwind(|| (Checked::new(2u8) - 3).expect("non-negative"))
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<String>().map(String::as_str), Some("non-negative"));
+        assert!(std::panic::catch_unwind(|| (Checked::new(2u8) - 3).unwrap()).is_err());
+    }
+
+    #[test]
+    fn fallbacks() {
+        assert_eq!(*(Checked::new(2u8) * 200).unwrap_or(u8::MAX), u8::MAX);
+        assert_eq!(*(Checked::new(2u8) * 100).unwrap_or_else(|| unreachable!()), 200);
+        assert_eq!(*(Checked::new(2u8) - 3).unwrap_or_default(), 0);
+        assert_eq!((Checked::new(2u8) - 3).ok_or("too small"), Err("too small"));
+    }
+
+    #[test]
+    fn saturate() {
+        let sat = |v: Unchecked<i32, WithDeref>| v.saturate().map(Checked::into_inner);
+        assert_eq!(sat(Checked::new(i32::MAX) * 2 - 5), Some(i32::MAX));
+        assert_eq!(sat(Checked::new(i32::MIN) * 2 + 5), Some(i32::MIN));
+        assert_eq!(sat(Checked::new(-3i32).pow(31)), Some(i32::MIN));
+        assert_eq!(sat(Checked::new(i32::MIN).abs() - 1), Some(i32::MAX));
+        assert_eq!(sat(Checked::new(i32::MIN) / -1), Some(i32::MAX));
+        assert_eq!(sat(Checked::new(3i32) * 2), Some(6));
+        assert_eq!(sat(Checked::new(i32::MAX) * 2 * -1), None);
+        assert_eq!(sat(Checked::new(i32::MAX) % 0), None);
+        assert_eq!(sat(Checked::new(i32::MAX).try_add(1u64) << 40), None);
+
+        let mut acc = Unchecked::<u8>::from(0u8);
+        acc -= 1;
+        acc += 100;
+        assert_eq!(acc.saturate().map(Checked::into_inner), Some(0));
+        assert_eq!(
+            (Checked::new(u16::MAX) + 1).narrow::<u8>().saturate().map(Checked::into_inner),
+            Some(u8::MAX)
+        );
+    }
+
+    #[test]
+    fn option_interop() {
+        let lifted: Unchecked<u32> = 7u32.checked_sub(2).into();
+        assert_eq!(Option::from(lifted * 3), Some(15u32));
+
+        let lifted: Unchecked<u32> = 2u32.checked_sub(7).into();
+        assert!((lifted + 10).check().is_none());
+        assert_eq!(Option::<u32>::from(Checked::new(u32::MAX) + 1), None);
+    }
+
+    #[test]
+    fn op_assign() {
+        let mut acc = Unchecked::<u64>::from(0u64);
+        for x in &[1u64, 2, 3] {
+            acc += *x;
+            acc *= Checked::new(2u64);
+        }
+        acc -= 1;
+        acc /= 2;
+        acc %= 7;
+        assert_eq!(acc.check().map(Checked::into_inner), Some(3));
+
+        let mut acc = Unchecked::<u8>::from(u8::MAX);
+        acc += 1;
+        acc -= 1;
+        assert!(acc.check().is_none());
+    }
+
+    #[test]
+    fn overflowing() {
+        assert_eq!(
+            { Checked::new(250u8).overflowing() + 10 - 5 }.check_wrapping(),
+            (255, true)
+        );
+        assert_eq!(
+            { Checked::new(2u8).overflowing() * 3 }.check_wrapping(),
+            (6, false)
+        );
+        assert!({ Checked::new(0u8).overflowing() - 1 + 1 }.check().is_none());
+    }
+
+    #[test]
+    fn check_in_range() {
+        assert_eq!(
+            { Checked::new(10u8) * 2 }.check_in_range(1..=20),
+            Ok(Checked::new(20))
+        );
+        assert_eq!(
+            { Checked::new(10u8) * 3 }.check_in_range(1..=20),
+            Err(CheckInRangeError::OutOfRange)
+        );
+        assert_eq!(
+            { Checked::new(10u8) * 30 }.check_in_range(..),
+            Err(CheckInRangeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn div_nonzero() {
+        let divisor = std::num::NonZeroU32::new(7).expect("non-zero");
+        assert_eq!(Checked::new(50u32).div_nonzero(divisor), Checked::new(7));
+        assert_eq!(Checked::new(50u32).rem_nonzero(divisor), Checked::new(1));
+
+        let divisor = { Checked::new(10u32) - 3 }.check_nonzero().expect("non-zero");
+        assert_eq!(Checked::new(50u32).div_nonzero(*divisor), Checked::new(7));
+        assert!({ Checked::new(10u32) - 10 }.check_nonzero().is_none());
+        assert!({ Checked::new(10i64) - i64::MIN }.check_nonzero().is_none());
+    }
+
+    #[test]
+    fn nonzero_ops() {
+        use std::num::{NonZeroI8, NonZeroU32};
+
+        let count = NonZeroU32::new(3).expect("non-zero");
+        let total = { Checked::new(count) * count + 1 }.check().expect("no overflow");
+        assert_eq!(total.get(), 10);
+        assert_eq!(Checked::new(count).pow(2).check().map(|v| v.get()), Some(9));
+
+        assert_eq!({ Checked::new(NonZeroU32::MAX) + count }.saturate(), Some(Checked::MAX));
+        assert!({ Checked::new(NonZeroU32::MAX) * count }.check().is_none());
+
+        let min = Checked::new(NonZeroI8::MIN);
+        assert!(min.abs().check().is_none());
+        assert!({ -min }.check().is_none());
+        assert_eq!({ -Checked::new(NonZeroI8::MAX) }.check().map(|v| v.get()), Some(-127));
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy_packet() {
+        use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+        #[derive(FromBytes, IntoBytes, Immutable, KnownLayout)]
+        #[repr(C)]
+        struct Header {
+            len: Checked<u32, WithoutDeref>,
+            kind: Checked<u16, WithoutDeref>,
+            flags: Checked<u16, WithoutDeref>,
+        }
+
+        let mut bytes = 7u32.to_ne_bytes().to_vec();
+        bytes.extend_from_slice(&1u16.to_ne_bytes());
+        bytes.extend_from_slice(&2u16.to_ne_bytes());
+        let header = Header::read_from_bytes(&bytes).expect("right size");
+        assert_eq!(header.len.into_inner(), 7);
+        assert_eq!((header.len + 1).check().map(Checked::into_inner), Some(8));
+        assert_eq!(header.as_bytes(), &bytes[..]);
+    }
+
+    #[test]
+    fn div_exact() {
+        assert_eq!(
+            Checked::new(3000u64).div_exact(1000).check(),
+            Some(Checked::new(3))
+        );
+        assert!(Checked::new(3001u64).div_exact(1000).check().is_none());
+        assert!({ Checked::new(3u64) * 1000 }.div_exact(0).check().is_none());
+        assert!(Checked::new(i8::MIN).div_exact(-1).check().is_none());
+    }
+
+    #[test]
+    fn narrowing() {
+        assert_eq!(Checked::new(300u32).narrow::<u8>().check(), None);
+        assert_eq!(
+            Checked::new(200u32).narrow::<u8>().check(),
+            Some(Checked::new(200u8))
+        );
+        assert_eq!(Checked::new(300u32).saturating_narrow::<u8>(), Checked::new(u8::MAX));
+        assert_eq!(Checked::new(-300i32).saturating_narrow::<i8>(), Checked::new(i8::MIN));
+        assert_eq!(Checked::new(-1i32).saturating_narrow::<u64>(), Checked::new(0u64));
+        assert_eq!(Checked::new(300u32).wrapping_narrow::<u8>(), Checked::new(44u8));
+        assert!({ Checked::new(20u64) * 20 }.narrow::<u8>().check().is_none());
+    }
+
+    #[test]
+    fn cast_sign() {
+        assert_eq!(Checked::new(255u8).cast_signed(), Checked::new(-1i8));
+        assert_eq!(Checked::new(i64::MIN).cast_unsigned(), Checked::new(1u64 << 63));
+    }
+
+    #[test]
+    fn cross_width_cmp() {
+        assert_eq!(Checked::new(300u64), Checked::new(300u16));
+        assert!(Checked::new(u32::MAX) < Checked::new(u64::MAX));
+        assert!(Checked::new(-1i8) < Checked::new(0u128));
+        assert!(Checked::new(u128::MAX) > Checked::new(i128::MAX));
+        assert!(Checked::new(i64::MIN) < Checked::new(i8::MIN));
+        assert!(Checked::new(1u8) != Checked::new(-1i64));
+    }
+
+    #[test]
+    fn widening_ops() {
+        let total = { Checked::new(u32::MAX) + Checked::new(1u64) }.check();
+        assert_eq!(*total.expect("no overflow"), u64::from(u32::MAX) + 1);
+
+        let diff = { Checked::new(5i64) - Checked::new(u32::MAX) }.check();
+        assert_eq!(*diff.expect("no overflow"), 5 - i64::from(u32::MAX));
+
+        assert!({ Checked::new(2u8) * Checked::new(u128::MAX) }.check().is_none());
+    }
+
+    #[test]
+    fn try_from_rhs() {
+        assert_eq!(
+            Checked::new(10u8).try_add(200i32).try_sub(10u64).check(),
+            Some(Checked::new(200u8))
+        );
+        assert!(Checked::new(10u8).try_add(300i32).check().is_none());
+        assert!(Checked::new(10u32).try_sub(-1i8).check().is_none());
+        assert_eq!(
+            Checked::new(-10i16).try_mul(3usize).check(),
+            Some(Checked::new(-30i16))
+        );
+        assert_eq!(
+            Checked::new(7u16).try_div(2i64).try_rem(2u8).check(),
+            Some(Checked::new(1u16))
+        );
+        assert!((Checked::new(1u8) + u8::MAX).try_add(0i32).check().is_none());
+    }
+
+    #[test]
+    fn literal_rhs() {
+        // Literals infer to the inner type, even out of range for `i32`
+        assert_eq!(
+            (Checked::new(1u64) + 5_000_000_000).check(),
+            Some(Checked::new(5_000_000_001u64))
+        );
+        assert_eq!(
+            (Checked::new(-1i128) * 10_000_000_000_000_000_000).check(),
+            Some(Checked::new(-10_000_000_000_000_000_000i128))
+        );
+        let mut acc = Checked::new(0u64) + 0;
+        acc += 4_294_967_296;
+        assert_eq!(acc.check(), Some(Checked::new(1u64 << 32)));
+    }
+
+    #[test]
+    fn hash_key() {
+        use std::collections::HashMap;
+
+        let mut balances = HashMap::new();
+        balances.insert(Checked::new_without_deref(7u64), 100);
+        balances.insert(Checked::new_without_deref(8u64), 200);
+
+        assert_eq!(balances.get(&Checked::new_without_deref(7u64)), Some(&100));
+        let key = { Checked::new_without_deref(4u64) * 2 }.check().expect("no overflow");
+        assert_eq!(balances.get(&key), Some(&200));
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Counters {
+            sent: Checked<u32>,
+            received: Checked<u64, WithoutDeref>,
+        }
+
+        let mut counters = Counters::default();
+        assert_eq!(*counters.sent, 0);
+        assert_eq!(counters.received.into_inner(), 0);
+
+        counters.received = { counters.received + 5 }.check().expect("no overflow");
+        assert_eq!(counters.received.into_inner(), 5);
+    }
+
+    #[test]
+    fn cmp_inner() {
+        let v = Checked::new_without_deref(5u32);
+        assert!(v == 5);
+        assert!(v != 6);
+        assert!(v < 100);
+        assert!(v >= 5);
+        assert!(5 == v);
+        assert!(100 > v);
+        assert!(4 < v);
+    }
+
+    #[test]
+    fn fmt() {
+        let v = Checked::new_without_deref(42u8);
+        assert_eq!(format!("{}", v), "42");
+        assert_eq!(format!("{:>4}", v), "  42");
+        assert_eq!(format!("{:x} {:X} {:#b} {:o}", v, v, v, v), "2a 2A 0b101010 52");
+        assert_eq!(format!("{:+}", Checked::new(-3i32)), "-3");
+    }
+
+    #[test]
+    fn as_ref_borrow() {
+        use std::collections::HashSet;
+
+        fn takes_ref(v: &u64) -> u64 {
+            *v
+        }
+
+        let v = Checked::new(7u64);
+        assert_eq!(takes_ref(v.as_ref()), 7);
+        let ids: HashSet<_> = vec![v].into_iter().collect();
+        assert!(ids.contains(&7u64));
+
+        let v = Checked::new_without_deref(8u64);
+        assert_eq!(takes_ref(v.as_inner()), 8);
+    }
+
+    #[test]
+    fn constants() {
+        fn headroom<T>(v: Checked<T, WithoutDeref>) -> Unchecked<T, WithoutDeref>
+        where
+            T: Bounded + CheckedSub<Output = T>,
+        {
+            Checked::<T, WithoutDeref>::MAX - v.into_inner()
+        }
+
+        assert_eq!(
+            headroom(Checked::new_without_deref(200u8)).check(),
+            Some(Checked::new_without_deref(55))
+        );
+        assert_eq!(Checked::<i16>::MIN, Checked::new(i16::MIN));
+        assert_eq!({ Checked::<u32>::ZERO + Checked::<u32>::ONE }.check(), Some(Checked::new(1)));
+    }
+
+    #[test]
+    fn floats() {
+        let area = { Checked::new(1.5f64) * 4.0 - 1.0 }.check().expect("finite");
+        assert_eq!(*area, 5.0);
+
+        assert!({ Checked::new(f64::MAX) * 2.0 }.check().is_none());
+        assert_eq!({ Checked::new(f32::MAX) + f32::MAX }.saturate(), Some(Checked::new(f32::MAX)));
+        assert_eq!({ -Checked::new(f32::MAX) * 2.0 + 1.0 }.saturate(), Some(Checked::new(f32::MIN)));
+        assert!({ Checked::new(1.0f64) / 0.0 }.saturate().is_none());
+        assert!({ Checked::new(0.0f64) % 0.0 }.check().is_none());
+        assert!({ Checked::new(f64::INFINITY) - 1.0 }.check().is_none());
+
+        assert_eq!(Checked::<f32>::new_finite(0.5), Some(Checked::new(0.5)));
+        assert!(Checked::<f64>::new_finite(f64::NAN).is_none());
+        assert!(Checked::<f64>::new_finite(f64::NEG_INFINITY).is_none());
+    }
 }
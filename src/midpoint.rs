@@ -0,0 +1,81 @@
+use super::{Checked, Midpoint, Overflow, Unchecked, WideSum};
+use core::convert::TryInto;
+use core::marker::PhantomData;
+
+impl<T, D> Checked<T, D> {
+    /// Midpoint of `self` and `rhs`, as if calculated in a wider type
+    ///
+    /// Unlike `(a + b) / 2` this can't overflow. Signed values are rounded
+    /// towards zero, unsigned ones down.
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// assert_eq!(*Checked::new(u32::MAX).midpoint(u32::MAX - 2), u32::MAX - 1);
+    /// assert_eq!(*Checked::new(-3i8).midpoint(0), -1);
+    /// ```
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+    pub fn midpoint<Rhs>(self, rhs: Rhs) -> Checked<<T as Midpoint<Rhs>>::Output, D>
+    where
+        T: Midpoint<Rhs>,
+    {
+        Checked {
+            v: Midpoint::midpoint(self.v, rhs),
+            _deref: self._deref,
+        }
+    }
+}
+
+macro_rules! impl_average {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Mean of `values`, rounded towards zero
+                ///
+                /// The sum is calculated in a wider type, so the only failure
+                /// is an empty slice.
+                pub fn average(values: &[$t]) -> Unchecked<$t, D> {
+                    let sum = <$wide as WideSum<$t>>::wide_sum(values);
+                    Unchecked {
+                        v: sum
+                            .checked_div(values.len() as $wide)
+                            .and_then(|avg| avg.try_into().ok()),
+                        overflow: Overflow::default(),
+                        _deref: PhantomData,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_average!(
+    usize => u128, isize => i128,
+    u8 => u128, i8 => i128,
+    u16 => u128, i16 => i128,
+    u32 => u128, i32 => i128,
+    u64 => u128, i64 => i128
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(*Checked::new(u128::MAX).midpoint(1), 1 << 127);
+        assert_eq!(*Checked::new(i64::MIN).midpoint(i64::MAX), 0);
+        assert_eq!(*Checked::new(7u8).midpoint(Checked::new(10u8)), 8);
+    }
+
+    #[test]
+    fn average() {
+        let avg = Checked::<u64>::average(&[u64::MAX, u64::MAX - 2, u64::MAX - 4]);
+        assert_eq!(*avg.check().unwrap(), u64::MAX - 2);
+        assert_eq!(
+            *Checked::<i8>::average(&[-128, -128, 127]).check().unwrap(),
+            -43
+        );
+        assert!(Checked::<u32>::average(&[]).check().is_none());
+    }
+}
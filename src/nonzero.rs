@@ -0,0 +1,240 @@
+use super::{
+    Bounded, Checked, CheckedAbs, CheckedAdd, CheckedMul, CheckedNeg, CheckedPow, One,
+    OverflowBound, Unchecked,
+};
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+macro_rules! impl_div_nonzero {
+    ($t:ty, $nz:ty) => {
+        impl<D> Checked<$t, D> {
+            /// Divide by a divisor that is known to be non-zero.
+            ///
+            /// Unlike `/`, this can't fail, so the result is [`Checked`] directly.
+            pub fn div_nonzero(self, rhs: $nz) -> Self {
+                Self {
+                    v: self.v / rhs,
+                    _deref: self._deref,
+                }
+            }
+
+            /// Calculate remainder of division by a divisor that is known to be non-zero.
+            ///
+            /// Unlike `%`, this can't fail, so the result is [`Checked`] directly.
+            pub fn rem_nonzero(self, rhs: $nz) -> Self {
+                Self {
+                    v: self.v % rhs,
+                    _deref: self._deref,
+                }
+            }
+        }
+    };
+}
+
+impl_div_nonzero!(usize, NonZeroUsize);
+impl_div_nonzero!(u8, NonZeroU8);
+impl_div_nonzero!(u16, NonZeroU16);
+impl_div_nonzero!(u32, NonZeroU32);
+impl_div_nonzero!(u64, NonZeroU64);
+impl_div_nonzero!(u128, NonZeroU128);
+
+macro_rules! impl_check_nonzero {
+    ($($t:ty, $nz:ty);*) => {
+        $(
+            impl<D> Unchecked<$t, D> {
+                /// Convert back to a [`Checked`] non-zero value, if no overflow occurred
+                /// and the value is not zero.
+                ///
+                /// Useful when the result is about to be used as a divisor.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn check_nonzero(self) -> Option<Checked<$nz, D>> {
+                    Some(Checked {
+                        v: <$nz>::new(self.into_parts().0?)?,
+                        _deref: PhantomData,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_check_nonzero!(
+    usize, NonZeroUsize; isize, NonZeroIsize;
+    u8, NonZeroU8; i8, NonZeroI8;
+    u16, NonZeroU16; i16, NonZeroI16;
+    u32, NonZeroU32; i32, NonZeroI32;
+    u64, NonZeroU64; i64, NonZeroI64;
+    u128, NonZeroU128; i128, NonZeroI128
+);
+
+// Non-zero values stay non-zero through these operations, so overflow is the
+// only failure. Only unsigned values can be added to, since a signed sum may be zero.
+macro_rules! impl_checked_nonzero {
+    (@common $t:ty, $nz:ty) => {
+        impl CheckedMul for $nz {
+            type Output = $nz;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_mul(self, rhs: $nz) -> Option<Self::Output> {
+                <$nz>::checked_mul(self, rhs)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_mul_bounded(self, rhs: $nz) -> Result<Self::Output, Option<OverflowBound>> {
+                <$nz>::checked_mul(self, rhs).ok_or_else(|| {
+                    if self.saturating_mul(rhs) == <$nz>::MAX {
+                        Some(OverflowBound::Max)
+                    } else {
+                        Some(OverflowBound::Min)
+                    }
+                })
+            }
+        }
+
+        impl<D> CheckedMul<Checked<$nz, D>> for $nz {
+            type Output = $nz;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_mul(self, rhs: Checked<$nz, D>) -> Option<Self::Output> {
+                <$nz>::checked_mul(self, rhs.v)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_mul_bounded(
+                self,
+                rhs: Checked<$nz, D>,
+            ) -> Result<Self::Output, Option<OverflowBound>> {
+                <$nz as CheckedMul>::checked_mul_bounded(self, rhs.v)
+            }
+        }
+
+        impl CheckedPow for $nz {
+            type Output = $nz;
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_pow(self, exp: u32) -> Option<Self::Output> {
+                <$nz>::checked_pow(self, exp)
+            }
+
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+            fn checked_pow_bounded(self, exp: u32) -> Result<Self::Output, Option<OverflowBound>> {
+                <$nz>::checked_pow(self, exp).ok_or_else(|| {
+                    if self.saturating_pow(exp) == <$nz>::MAX {
+                        Some(OverflowBound::Max)
+                    } else {
+                        Some(OverflowBound::Min)
+                    }
+                })
+            }
+        }
+
+        impl Bounded for $nz {
+            const MIN: Self = <$nz>::MIN;
+            const MAX: Self = <$nz>::MAX;
+        }
+
+        impl One for $nz {
+            const ONE: Self = match <$nz>::new(1) {
+                Some(one) => one,
+                None => unreachable!(),
+            };
+        }
+    };
+    (unsigned $($t:ty, $nz:ty);*) => {
+        $(
+            impl_checked_nonzero!(@common $t, $nz);
+
+            impl CheckedAdd<$t> for $nz {
+                type Output = $nz;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add(self, rhs: $t) -> Option<Self::Output> {
+                    <$nz>::checked_add(self, rhs)
+                }
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add_bounded(self, rhs: $t) -> Result<Self::Output, Option<OverflowBound>> {
+                    <$nz>::checked_add(self, rhs).ok_or(Some(OverflowBound::Max))
+                }
+            }
+
+            impl CheckedAdd for $nz {
+                type Output = $nz;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add(self, rhs: $nz) -> Option<Self::Output> {
+                    <$nz>::checked_add(self, rhs.get())
+                }
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add_bounded(self, rhs: $nz) -> Result<Self::Output, Option<OverflowBound>> {
+                    <$nz>::checked_add(self, rhs.get()).ok_or(Some(OverflowBound::Max))
+                }
+            }
+
+            impl<D> CheckedAdd<Checked<$nz, D>> for $nz {
+                type Output = $nz;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add(self, rhs: Checked<$nz, D>) -> Option<Self::Output> {
+                    <$nz>::checked_add(self, rhs.v.get())
+                }
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_add_bounded(
+                    self,
+                    rhs: Checked<$nz, D>,
+                ) -> Result<Self::Output, Option<OverflowBound>> {
+                    <$nz as CheckedAdd>::checked_add_bounded(self, rhs.v)
+                }
+            }
+        )*
+    };
+    (signed $($t:ty, $nz:ty);*) => {
+        $(
+            impl_checked_nonzero!(@common $t, $nz);
+
+            // Only `MIN` overflows, and its absolute value or negation would be above `MAX`
+            impl CheckedAbs for $nz {
+                type Output = $nz;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_abs(self) -> Option<Self> {
+                    <$nz>::checked_abs(self)
+                }
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_abs_bounded(self) -> Result<Self, Option<OverflowBound>> {
+                    <$nz>::checked_abs(self).ok_or(Some(OverflowBound::Max))
+                }
+            }
+
+            impl CheckedNeg for $nz {
+                type Output = $nz;
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_neg(self) -> Option<Self> {
+                    <$nz>::checked_neg(self)
+                }
+
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                fn checked_neg_bounded(self) -> Result<Self, Option<OverflowBound>> {
+                    <$nz>::checked_neg(self).ok_or(Some(OverflowBound::Max))
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_nonzero!(unsigned
+    usize, NonZeroUsize; u8, NonZeroU8; u16, NonZeroU16;
+    u32, NonZeroU32; u64, NonZeroU64; u128, NonZeroU128
+);
+
+impl_checked_nonzero!(signed
+    isize, NonZeroIsize; i8, NonZeroI8; i16, NonZeroI16;
+    i32, NonZeroI32; i64, NonZeroI64; i128, NonZeroI128
+);
@@ -0,0 +1,123 @@
+use super::{
+    Checked, CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub,
+};
+use ::num_bigint::{BigInt, BigUint};
+use ::num_traits::Zero;
+
+// Big integers grow instead of overflowing, so only division by zero
+// and going below zero for `BigUint` can fail.
+macro_rules! impl_checked_op_big {
+    ($t:ty, $checked_t:ident, $checked_op:ident, |$l:ident, $r:ident| $body:expr) => {
+        impl $checked_t for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
+                let ($l, $r) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<D> $checked_t<Checked<$t, D>> for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: Checked<$t, D>) -> Option<Self::Output> {
+                let ($l, $r) = (self, rhs.v);
+                $body
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_ops_big {
+    ($($t:ty),*) => {
+        $(
+            impl_checked_op_big!($t, CheckedAdd, checked_add, |l, r| Some(l + r));
+            impl_checked_op_big!($t, CheckedMul, checked_mul, |l, r| Some(l * r));
+            impl_checked_op_big!($t, CheckedDiv, checked_div, |l, r| if r.is_zero() {
+                None
+            } else {
+                Some(l / r)
+            });
+            impl_checked_op_big!($t, CheckedRem, checked_rem, |l, r| if r.is_zero() {
+                None
+            } else {
+                Some(l % r)
+            });
+        )*
+    };
+}
+
+impl_checked_ops_big!(BigInt, BigUint);
+
+impl_checked_op_big!(BigInt, CheckedSub, checked_sub, |l, r| Some(l - r));
+impl_checked_op_big!(BigUint, CheckedSub, checked_sub, |l, r| if r > l {
+    None
+} else {
+    Some(l - r)
+});
+
+impl CheckedNeg for BigInt {
+    type Output = BigInt;
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl CheckedNeg for BigUint {
+    type Output = BigUint;
+
+    fn checked_neg(self) -> Option<Self> {
+        if self.is_zero() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl CheckedAbs for BigInt {
+    type Output = BigInt;
+
+    fn checked_abs(self) -> Option<Self> {
+        Some(::num_traits::Signed::abs(&self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_uint() {
+        let big = BigUint::from(u128::MAX);
+        let sq = { Checked::new(big.clone()) * big.clone() + BigUint::from(1u8) }
+            .check()
+            .expect("no overflow");
+        assert_eq!(*sq, &big * &big + 1u8);
+
+        assert!({ Checked::new(BigUint::from(1u8)) - BigUint::from(2u8) }
+            .check()
+            .is_none());
+        assert!({ Checked::new(big) / BigUint::zero() }.check().is_none());
+        assert!({ -Checked::new(BigUint::from(1u8)) }.check().is_none());
+    }
+
+    #[test]
+    fn big_int() {
+        let a = Checked::new_without_deref(BigInt::from(i128::MIN));
+        let b = Checked::new_without_deref(BigInt::from(-1));
+        let q = { a.clone() / b.clone() - b }.check().expect("no overflow");
+        assert_eq!(q.into_inner(), BigInt::from(i128::MAX) + 2);
+
+        assert_eq!(
+            *{ -Checked::new(BigInt::from(5)) }.check().unwrap(),
+            BigInt::from(-5)
+        );
+        assert_eq!(
+            *Checked::new(BigInt::from(-5)).abs().check().unwrap(),
+            BigInt::from(5)
+        );
+        assert!({ a % BigInt::zero() }.check().is_none());
+    }
+}
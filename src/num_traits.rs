@@ -0,0 +1,162 @@
+use super::Checked;
+use ::num_traits::{Bounded, NumCast, ToPrimitive};
+use core::marker::PhantomData;
+
+#[doc(hidden)]
+pub use ::num_traits as __num_traits;
+
+// `Checked` arithmetic results in `Unchecked`, so the `num_traits` traits built
+// on `Add<Output = Self>` and similar (`Zero`, `CheckedAdd`, ...) don't fit it.
+
+impl<T, D> Bounded for Checked<T, D>
+where
+    T: Bounded,
+{
+    fn min_value() -> Self {
+        Self {
+            v: T::min_value(),
+            _deref: PhantomData,
+        }
+    }
+
+    fn max_value() -> Self {
+        Self {
+            v: T::max_value(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+impl<T, D> ToPrimitive for Checked<T, D>
+where
+    T: ToPrimitive,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.v.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.v.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.v.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.v.to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.v.to_f64()
+    }
+}
+
+impl<T, D> NumCast for Checked<T, D>
+where
+    T: NumCast,
+{
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Some(Self {
+            v: T::from(n)?,
+            _deref: PhantomData,
+        })
+    }
+}
+
+/// Implement the base checked traits for a type implementing the `num_traits` ones
+///
+/// Like [`impl_checked_ops_for!`](crate::impl_checked_ops_for), but the operations
+/// are taken from `num_traits::CheckedAdd` and friends, so any type from the `num`
+/// ecosystem can be used as the inner type of [`Checked`]. A blanket impl isn't
+/// possible, as it would overlap with the impls for primitive types.
+///
+/// By default `add`, `sub`, `mul`, `div` and `rem` are implemented. A subset,
+/// or additionally `neg`, can be selected by listing them after the type.
+///
+/// ```
+/// use overflow_proof::{impl_num_traits_ops_for, Checked};
+/// use std::ops::{Add, Sub};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Cents(u32);
+///
+/// impl Add for Cents {
+///     type Output = Cents;
+///
+///     fn add(self, rhs: Self) -> Self {
+///         Cents(self.0 + rhs.0)
+///     }
+/// }
+///
+/// impl num_traits::CheckedAdd for Cents {
+///     fn checked_add(&self, rhs: &Self) -> Option<Self> {
+///         self.0.checked_add(rhs.0).map(Cents)
+///     }
+/// }
+///
+/// impl_num_traits_ops_for!(Cents; add);
+///
+/// assert_eq!((Checked::new(Cents(1)) + Cents(2)).check().map(|c| *c), Some(Cents(3)));
+/// assert!((Checked::new(Cents(u32::MAX)) + Cents(1)).check().is_none());
+/// ```
+#[macro_export]
+macro_rules! impl_num_traits_ops_for {
+    (@op $t:ty, add) => { $crate::impl_num_traits_ops_for!(@2 $t, CheckedAdd, checked_add); };
+    (@op $t:ty, sub) => { $crate::impl_num_traits_ops_for!(@2 $t, CheckedSub, checked_sub); };
+    (@op $t:ty, mul) => { $crate::impl_num_traits_ops_for!(@2 $t, CheckedMul, checked_mul); };
+    (@op $t:ty, div) => { $crate::impl_num_traits_ops_for!(@2 $t, CheckedDiv, checked_div); };
+    (@op $t:ty, rem) => { $crate::impl_num_traits_ops_for!(@2 $t, CheckedRem, checked_rem); };
+    (@op $t:ty, neg) => {
+        impl $crate::CheckedNeg for $t {
+            type Output = $t;
+
+            fn checked_neg(self) -> Option<Self::Output> {
+                $crate::__num_traits::CheckedNeg::checked_neg(&self)
+            }
+        }
+    };
+    (@2 $t:ty, $checked_t:ident, $checked_op:ident) => {
+        impl $crate::$checked_t for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: Self) -> Option<Self::Output> {
+                $crate::__num_traits::$checked_t::$checked_op(&self, &rhs)
+            }
+        }
+
+        impl<D> $crate::$checked_t<$crate::Checked<$t, D>> for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: $crate::Checked<$t, D>) -> Option<Self::Output> {
+                $crate::__num_traits::$checked_t::$checked_op(&self, &rhs.into_inner())
+            }
+        }
+    };
+    ($t:ty) => {
+        $crate::impl_num_traits_ops_for!($t; add, sub, mul, div, rem);
+    };
+    ($t:ty; $($op:ident),+ $(,)?) => {
+        $( $crate::impl_num_traits_ops_for!(@op $t, $op); )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn generic() {
+        fn widest<T: Bounded>() -> T {
+            T::max_value()
+        }
+
+        let max: Checked<u16, WithoutDeref> = widest();
+        assert_eq!(max, Checked::new_without_deref(u16::MAX));
+        assert_eq!(Checked::new(300u32).to_u8(), None);
+        assert_eq!(Checked::new(-3i32).to_i64(), Some(-3));
+        assert_eq!(<Checked<u8> as NumCast>::from(255u64), Some(Checked::new(255)));
+        assert_eq!(<Checked<u8> as NumCast>::from(-1i64), None);
+    }
+}
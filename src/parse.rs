@@ -0,0 +1,267 @@
+use super::{Checked, Overflow, Unchecked};
+use core::fmt;
+use core::marker::PhantomData;
+use core::num::ParseIntError;
+use core::str::FromStr;
+
+/// Error returned by [`Checked::parse_ascii()`] and [`Checked::parse_ascii_radix()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseAsciiError {
+    /// No digits to parse
+    Empty,
+    /// Byte that is not a valid digit in the given radix, or a misplaced sign
+    InvalidDigit,
+    /// Radix outside of `2..=36`
+    InvalidRadix,
+    /// The number doesn't fit in the target type
+    Overflow,
+}
+
+impl fmt::Display for ParseAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseAsciiError::Empty => "cannot parse integer from empty input",
+            ParseAsciiError::InvalidDigit => "invalid digit found in input",
+            ParseAsciiError::InvalidRadix => "radix must be in range 2..=36",
+            ParseAsciiError::Overflow => "number too large or too small to fit in target type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAsciiError {}
+
+macro_rules! impl_parse_ascii {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Parse an integer in the given `radix`, same as the inner type's `from_str_radix`.
+                ///
+                /// # Panics
+                ///
+                /// If `radix` is not in `2..=36`, like the primitive method.
+                pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    Ok(Self::from(<$t>::from_str_radix(s, radix)?))
+                }
+
+                /// Parse a decimal integer from ASCII bytes, with an optional `+`/`-` sign.
+                ///
+                /// Doesn't require the input to be valid UTF-8 or go through `str`.
+                pub fn parse_ascii(bytes: &[u8]) -> Result<Self, ParseAsciiError> {
+                    Self::parse_ascii_radix(bytes, 10)
+                }
+
+                /// Parse an integer in the given `radix` from ASCII bytes, with an optional `+`/`-` sign.
+                ///
+                /// Overflow is reported separately from syntax errors.
+                #[allow(unused_comparisons)]
+                pub fn parse_ascii_radix(bytes: &[u8], radix: u32) -> Result<Self, ParseAsciiError> {
+                    if !(2..=36).contains(&radix) {
+                        return Err(ParseAsciiError::InvalidRadix);
+                    }
+                    let (neg, digits) = match bytes {
+                        [b'+', rest @ ..] => (false, rest),
+                        [b'-', rest @ ..] if <$t>::MIN < 0 => (true, rest),
+                        _ => (false, bytes),
+                    };
+                    if digits.is_empty() {
+                        return Err(ParseAsciiError::Empty);
+                    }
+
+                    let mut v: $t = 0;
+                    for &b in digits {
+                        let digit = char::from(b)
+                            .to_digit(radix)
+                            .ok_or(ParseAsciiError::InvalidDigit)? as $t;
+                        v = v
+                            .checked_mul(radix as $t)
+                            .and_then(|v| {
+                                if neg {
+                                    v.checked_sub(digit)
+                                } else {
+                                    v.checked_add(digit)
+                                }
+                            })
+                            .ok_or(ParseAsciiError::Overflow)?;
+                    }
+
+                    Ok(Self::from(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_ascii!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Parses with the inner type's own `FromStr`, so errors are the same as for `T`
+///
+/// Like [`Checked::new()`], it accepts any value of `T`, including non-finite
+/// floats; see [`Checked::new_finite()`].
+impl<T, D> FromStr for Checked<T, D>
+where
+    T: FromStr,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            v: s.parse()?,
+            _deref: PhantomData,
+        })
+    }
+}
+
+/// What an overflowed `Unchecked` is displayed as, and parsed back from
+const OVERFLOW_TOKEN: &str = "overflow";
+
+/// Displays the value, or `overflow` if it overflowed, which its `FromStr` parses back
+///
+/// ```
+/// use overflow_proof::{Checked, Unchecked};
+///
+/// let total = Checked::new(200u8) + 100;
+/// assert_eq!(total.to_string(), "overflow");
+/// assert!(total.to_string().parse::<Unchecked<u8>>().unwrap().check().is_none());
+/// ```
+impl<T, D> fmt::Display for Unchecked<T, D>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.v {
+            Some(v) => v.fmt(f),
+            None => f.pad(OVERFLOW_TOKEN),
+        }
+    }
+}
+
+macro_rules! impl_parse_lenient {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Unchecked<$t, D> {
+                /// Parse a decimal integer, where a number that doesn't fit in the
+                /// inner type results in overflow instead of an error.
+                ///
+                /// Only syntax errors are reported, so for example a column of values
+                /// can be parsed in bulk, leaving out-of-range values to be dealt with
+                /// at [`Unchecked::check()`].
+                #[allow(unused_comparisons)]
+                pub fn parse_lenient(s: &str) -> Result<Self, ParseAsciiError> {
+                    let v = match Checked::<$t, D>::parse_ascii(s.as_bytes()) {
+                        Ok(v) => Some(v.v),
+                        Err(ParseAsciiError::Overflow) => None,
+                        // A negative number is valid syntax, just out of range for unsigned types
+                        Err(ParseAsciiError::InvalidDigit) if <$t>::MIN == 0 && s.starts_with('-') && !s.starts_with("-+") => {
+                            let v = Checked::<$t, D>::parse_ascii(&s.as_bytes()[1..])
+                                .or_else(|e| match e {
+                                    ParseAsciiError::Overflow => Ok(Checked::from(<$t>::MAX)),
+                                    e => Err(e),
+                                })?;
+                            if v.v == 0 {
+                                Some(0)
+                            } else {
+                                None
+                            }
+                        }
+                        Err(e) => return Err(e),
+                    };
+                    Ok(Self {
+                        v,
+                        overflow: Overflow::default(),
+                        _deref: PhantomData,
+                    })
+                }
+            }
+
+            impl<D> FromStr for Unchecked<$t, D> {
+                type Err = ParseAsciiError;
+
+                /// Same as [`Unchecked::parse_lenient()`], but also accepts `overflow`
+                /// for a value that already overflowed.
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    if s == OVERFLOW_TOKEN {
+                        return Ok(Self {
+                            v: None,
+                            overflow: Overflow::default(),
+                            _deref: PhantomData,
+                        });
+                    }
+                    Self::parse_lenient(s)
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_lenient!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ascii() {
+        assert_eq!(Checked::<u8>::parse_ascii(b"255"), Ok(Checked::new(255)));
+        assert_eq!(Checked::<u8>::parse_ascii(b"+7"), Ok(Checked::new(7)));
+        assert_eq!(Checked::<u8>::parse_ascii(b"256"), Err(ParseAsciiError::Overflow));
+        assert_eq!(Checked::<u8>::parse_ascii(b"-1"), Err(ParseAsciiError::InvalidDigit));
+        assert_eq!(Checked::<i8>::parse_ascii(b"-128"), Ok(Checked::new(-128)));
+        assert_eq!(Checked::<i8>::parse_ascii(b"-129"), Err(ParseAsciiError::Overflow));
+        assert_eq!(Checked::<i8>::parse_ascii(b"-"), Err(ParseAsciiError::Empty));
+        assert_eq!(Checked::<u32>::parse_ascii(b"1 2"), Err(ParseAsciiError::InvalidDigit));
+        assert_eq!(Checked::<u32>::parse_ascii_radix(b"fF", 16), Ok(Checked::new(255)));
+        assert_eq!(Checked::<u32>::parse_ascii_radix(b"1", 37), Err(ParseAsciiError::InvalidRadix));
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let parsed: Vec<Unchecked<u8>> = ["12", "256", "-1", "-0", "-99999"]
+            .iter()
+            .map(|s| s.parse().expect("valid syntax"))
+            .collect();
+        let checked: Vec<_> = parsed.into_iter().map(|v| v.check().map(|v| v.into_inner())).collect();
+        assert_eq!(checked, [Some(12), None, None, Some(0), None]);
+
+        assert_eq!("-+1".parse::<Unchecked<u8>>().unwrap_err(), ParseAsciiError::InvalidDigit);
+        assert_eq!("--1".parse::<Unchecked<u8>>().unwrap_err(), ParseAsciiError::InvalidDigit);
+        assert_eq!("".parse::<Unchecked<i8>>().unwrap_err(), ParseAsciiError::Empty);
+        assert!("-129".parse::<Unchecked<i8>>().unwrap().check().is_none());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("255".parse::<Checked<u8>>(), Ok(Checked::new(255)));
+        assert!("256".parse::<Checked<u8>>().is_err());
+        assert!(" 1".parse::<Checked<u8>>().is_err());
+
+        let parsed: Unchecked<u8> = "overflow".parse().expect("valid syntax");
+        assert!(parsed.check().is_none());
+        assert_eq!("Overflow".parse::<Unchecked<u8>>().unwrap_err(), ParseAsciiError::InvalidDigit);
+        assert!(Unchecked::<u8>::parse_lenient("overflow").is_err());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let values = [Checked::new(-5i64) * 3, Checked::new(i64::MAX) + 1];
+        let written: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        assert_eq!(written, ["-15", "overflow"]);
+
+        let read: Vec<Unchecked<i64>> = written
+            .iter()
+            .map(|s| s.parse().expect("valid syntax"))
+            .collect();
+        let checked: Vec<_> = read.into_iter().map(|v| v.check().map(|v| v.into_inner())).collect();
+        assert_eq!(checked, [Some(-15), None]);
+
+        assert_eq!(format!("{:>10}|{:<4}|", Checked::new(1u8) - 2, Checked::new(1u8) + 2), "  overflow|3   |");
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(Checked::<u16>::from_str_radix("ffFF", 16), Ok(Checked::new(u16::MAX)));
+        assert_eq!(Checked::<i8>::from_str_radix("-1010", 2), Ok(Checked::new(-10)));
+        assert!(Checked::<u16>::from_str_radix("10000", 16).is_err());
+        assert!(Checked::<u16>::from_str_radix("0x10", 16).is_err());
+    }
+}
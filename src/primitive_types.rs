@@ -0,0 +1,45 @@
+use super::{Bounded, One, Zero};
+use ::primitive_types::{U256, U512};
+
+macro_rules! impl_primitive_types {
+    ($($t:ident),*) => {
+        $(
+            crate::impl_checked_ops_for!($t; add, sub, mul, div, rem, neg);
+
+            impl Bounded for $t {
+                const MIN: Self = $t::zero();
+                const MAX: Self = $t::MAX;
+            }
+
+            impl Zero for $t {
+                const ZERO: Self = $t::zero();
+            }
+
+            impl One for $t {
+                const ONE: Self = $t::one();
+            }
+        )*
+    };
+}
+
+impl_primitive_types!(U256, U512);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Checked;
+
+    #[test]
+    fn u256() {
+        let wei = Checked::new_without_deref(U256::exp10(18));
+        let balance = { wei * U256::from(1_000u32) - U256::one() }
+            .check()
+            .expect("no overflow");
+        assert_eq!(balance.into_inner(), U256::exp10(21) - 1);
+
+        assert!({ Checked::<U256>::MAX + U256::one() }.check().is_none());
+        assert!({ Checked::<U256>::ZERO - U256::one() }.check().is_none());
+        assert!({ wei / U256::zero() }.check().is_none());
+        assert!({ -Checked::new(U512::one()) }.check().is_none());
+    }
+}
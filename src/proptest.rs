@@ -0,0 +1,69 @@
+//! ```
+//! use overflow_proof::proptest::{checked, checked_in_range};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn add_is_commutative(a in checked::<u32, _>(), b in checked_in_range(0u32..=10)) {
+//!         prop_assert_eq!({ a + b }.check(), { b + a }.check());
+//!     }
+//! }
+//! ```
+use super::{Checked, Unchecked};
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::option;
+use ::proptest::strategy::Strategy;
+use core::fmt;
+
+/// Any value of the inner type
+pub fn checked<T, D>() -> impl Strategy<Value = Checked<T, D>>
+where
+    T: Arbitrary,
+    D: fmt::Debug,
+{
+    any::<T>().prop_map(Checked::from)
+}
+
+/// Values of the inner type generated by `range`, usually a range like `1..=100`
+pub fn checked_in_range<T, D, R>(range: R) -> impl Strategy<Value = Checked<T, D>>
+where
+    T: fmt::Debug,
+    D: fmt::Debug,
+    R: Strategy<Value = T>,
+{
+    range.prop_map(Checked::from)
+}
+
+/// Any value of the inner type, or, roughly one time in eight, an overflow
+///
+/// The bound an overflow went past is never known, so [`Unchecked::saturate()`]
+/// returns `None` for those.
+pub fn unchecked_maybe_overflowed<T, D>() -> impl Strategy<Value = Unchecked<T, D>>
+where
+    T: Arbitrary,
+    D: fmt::Debug,
+{
+    option::weighted(0.875, any::<T>()).prop_map(Unchecked::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+    use ::proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn in_range(v in checked_in_range::<_, WithoutDeref, _>(10u8..=20)) {
+            assert!((10..=20).contains(&v.into_inner()));
+        }
+
+        #[test]
+        fn add_then_sub(a in checked::<u16, WithoutDeref>(), b in unchecked_maybe_overflowed::<u16, WithoutDeref>()) {
+            let expected = b.check();
+            if let Some(sum) = { b + a }.check() {
+                assert_eq!({ sum - a }.check(), expected);
+            }
+        }
+    }
+}
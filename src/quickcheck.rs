@@ -0,0 +1,41 @@
+use super::Checked;
+use ::quickcheck::{Arbitrary, Gen};
+
+impl<T, D> Arbitrary for Checked<T, D>
+where
+    T: Arbitrary,
+    D: 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Checked::from(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.v.shrink().map(Checked::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+    use ::quickcheck::{QuickCheck, TestResult};
+
+    #[test]
+    fn shrink() {
+        let v = Checked::<u32, WithoutDeref>::from(100);
+        assert!(v.shrink().all(|s| s.into_inner() < 100));
+        assert!(v.shrink().any(|s| s.into_inner() == 0));
+    }
+
+    #[test]
+    fn add_then_sub() {
+        fn prop(a: Checked<u16, WithoutDeref>, b: Checked<u16, WithoutDeref>) -> TestResult {
+            match { a + b }.check() {
+                Some(sum) => TestResult::from_bool({ sum - b }.check() == Some(a)),
+                None => TestResult::discard(),
+            }
+        }
+        QuickCheck::new().quickcheck(prop as fn(_, _) -> TestResult);
+    }
+}
@@ -0,0 +1,115 @@
+use super::{Checked, CheckedAdd, CheckedSub, WithoutDeref};
+use core::fmt;
+
+/// Error returned by [`Quota`] operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaError {
+    /// Not enough capacity available to reserve
+    Exceeded,
+    /// Attempt to release more than was reserved
+    OverRelease,
+}
+
+impl fmt::Display for QuotaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            QuotaError::Exceeded => "quota exceeded",
+            QuotaError::OverRelease => "released more than was reserved",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QuotaError {}
+
+/// Capacity tracker with reserve and release
+///
+/// Keeps track of how much of a fixed capacity is available, never
+/// allowing it to underflow below zero or grow above the capacity.
+///
+/// ```
+/// use overflow_proof::{Checked, Quota, QuotaError};
+///
+/// let mut connections = Quota::new(Checked::new_without_deref(10u32));
+/// connections.try_reserve(8).expect("available");
+/// assert_eq!(connections.try_reserve(3), Err(QuotaError::Exceeded));
+/// connections.release(8).expect("was reserved");
+/// assert_eq!(connections.release(1), Err(QuotaError::OverRelease));
+/// assert_eq!(connections.available().into_inner(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quota<T> {
+    capacity: Checked<T, WithoutDeref>,
+    available: Checked<T, WithoutDeref>,
+}
+
+impl<T> Quota<T>
+where
+    T: Clone,
+{
+    /// Create a quota with all of `capacity` available
+    pub fn new(capacity: Checked<T, WithoutDeref>) -> Self {
+        Self {
+            available: capacity.clone(),
+            capacity,
+        }
+    }
+
+    /// Total capacity
+    pub fn capacity(&self) -> Checked<T, WithoutDeref> {
+        self.capacity.clone()
+    }
+
+    /// Capacity not currently reserved
+    pub fn available(&self) -> Checked<T, WithoutDeref> {
+        self.available.clone()
+    }
+}
+
+impl<T> Quota<T>
+where
+    T: Clone + PartialOrd + CheckedAdd<Output = T> + CheckedSub<Output = T>,
+{
+    /// Reserve `n` out of the available capacity
+    ///
+    /// Fails without any change if less than `n` is available.
+    pub fn try_reserve(&mut self, n: T) -> Result<(), QuotaError> {
+        let available = self
+            .available
+            .v
+            .clone()
+            .checked_sub(n)
+            .ok_or(QuotaError::Exceeded)?;
+        self.available.v = available;
+        Ok(())
+    }
+
+    /// Release `n` previously reserved
+    ///
+    /// Fails without any change if less than `n` is currently reserved.
+    pub fn release(&mut self, n: T) -> Result<(), QuotaError> {
+        let available = self
+            .available
+            .v
+            .clone()
+            .checked_add(n)
+            .filter(|available| *available <= self.capacity.v)
+            .ok_or(QuotaError::OverRelease)?;
+        self.available.v = available;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_near_max() {
+        let mut quota = Quota::new(Checked::new_without_deref(u8::MAX));
+        assert_eq!(quota.release(1), Err(QuotaError::OverRelease));
+        quota.try_reserve(u8::MAX).expect("available");
+        assert_eq!(quota.try_reserve(1), Err(QuotaError::Exceeded));
+        assert_eq!(quota.available().into_inner(), 0);
+    }
+}
@@ -0,0 +1,89 @@
+use super::Checked;
+use ::rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+use core::marker::PhantomData;
+
+impl<T, D> Distribution<Checked<T, D>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Checked<T, D> {
+        Checked::from(self.sample(rng))
+    }
+}
+
+/// [`UniformSampler`] for [`Checked`], sampling the inner type
+pub struct UniformChecked<T, D>
+where
+    T: SampleUniform,
+{
+    inner: T::Sampler,
+    _deref: PhantomData<D>,
+}
+
+impl<T, D> UniformSampler for UniformChecked<T, D>
+where
+    T: SampleUniform,
+{
+    type X = Checked<T, D>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            inner: T::Sampler::new(&low.borrow().v, &high.borrow().v),
+            _deref: PhantomData,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            inner: T::Sampler::new_inclusive(&low.borrow().v, &high.borrow().v),
+            _deref: PhantomData,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Checked::from(self.inner.sample(rng))
+    }
+}
+
+impl<T, D> SampleUniform for Checked<T, D>
+where
+    T: SampleUniform,
+{
+    type Sampler = UniformChecked<T, D>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn sample() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let v: Checked<u8> = rng.gen();
+        assert!({ v + 0 }.check().is_some());
+
+        let lo = Checked::new_without_deref(10u32);
+        let hi = Checked::new_without_deref(20u32);
+        for _ in 0..100 {
+            let v = rng.gen_range(lo..hi);
+            assert!(lo <= v && v < hi);
+            let v = rng.gen_range(lo..=hi);
+            assert!(lo <= v && v <= hi);
+        }
+        let single: Checked<u32, WithoutDeref> = rng.gen_range(lo..=lo);
+        assert_eq!(single, lo);
+    }
+}
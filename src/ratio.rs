@@ -0,0 +1,117 @@
+use super::WideningMul;
+use core::cmp::Ordering;
+
+/// Types whose ratios can be compared exactly
+///
+/// See [`cmp_ratio()`].
+pub trait CmpRatio: Sized {
+    fn cmp_ratio(a_num: Self, a_den: Self, b_num: Self, b_den: Self) -> Option<Ordering>;
+}
+
+macro_rules! impl_cmp_ratio {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl CmpRatio for $t {
+                #[allow(unused_comparisons)]
+                fn cmp_ratio(a_num: Self, a_den: Self, b_num: Self, b_den: Self) -> Option<Ordering> {
+                    if a_den == 0 || b_den == 0 {
+                        return None;
+                    }
+                    let lhs = (a_num as $wide) * (b_den as $wide);
+                    let rhs = (b_num as $wide) * (a_den as $wide);
+                    let ord = lhs.cmp(&rhs);
+                    Some(if (a_den < 0) != (b_den < 0) { ord.reverse() } else { ord })
+                }
+            }
+        )*
+    };
+}
+
+impl_cmp_ratio!(
+    usize => u128, isize => i128,
+    u8 => u16, i8 => i16,
+    u16 => u32, i16 => i32,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128
+);
+
+macro_rules! impl_cmp_ratio_double_wide {
+    ($($t:ty),*) => {
+        $(
+            impl CmpRatio for $t {
+                #[allow(unused_comparisons)]
+                fn cmp_ratio(a_num: Self, a_den: Self, b_num: Self, b_den: Self) -> Option<Ordering> {
+                    if a_den == 0 || b_den == 0 {
+                        return None;
+                    }
+                    let lhs = WideningMul::widening_mul(a_num, b_den);
+                    let rhs = WideningMul::widening_mul(b_num, a_den);
+                    let ord = lhs.cmp(&rhs);
+                    Some(if (a_den < 0) != (b_den < 0) { ord.reverse() } else { ord })
+                }
+            }
+        )*
+    };
+}
+
+impl_cmp_ratio_double_wide!(u128, i128);
+
+/// Compare `a_num / a_den` with `b_num / b_den` exactly
+///
+/// The products of cross-multiplication are calculated in double width,
+/// so neither overflow nor truncation of division can affect the result.
+/// Returns `None` if any of the denominators is zero.
+///
+/// ```
+/// use overflow_proof::{cmp_ratio, ratio_ge};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(cmp_ratio(u64::MAX, u64::MAX - 1, 1, 1), Some(Ordering::Greater));
+/// assert_eq!(cmp_ratio(1, 0, 1, 1), None);
+///
+/// // `usage * 4` would overflow `u32`
+/// let (usage, capacity) = (3_000_000_000u32, 4_000_000_000);
+/// assert_eq!(ratio_ge(usage, capacity, 3, 4), Some(true));
+/// assert_eq!(ratio_ge(usage - 1, capacity, 3, 4), Some(false));
+/// ```
+pub fn cmp_ratio<T>(a_num: T, a_den: T, b_num: T, b_den: T) -> Option<Ordering>
+where
+    T: CmpRatio,
+{
+    T::cmp_ratio(a_num, a_den, b_num, b_den)
+}
+
+/// Is `a_num / a_den` greater or equal to `b_num / b_den`?
+///
+/// See [`cmp_ratio()`].
+pub fn ratio_ge<T>(a_num: T, a_den: T, b_num: T, b_den: T) -> Option<bool>
+where
+    T: CmpRatio,
+{
+    cmp_ratio(a_num, a_den, b_num, b_den).map(|ord| ord != Ordering::Less)
+}
+
+/// Is `a_num / a_den` less or equal to `b_num / b_den`?
+///
+/// See [`cmp_ratio()`].
+pub fn ratio_le<T>(a_num: T, a_den: T, b_num: T, b_den: T) -> Option<bool>
+where
+    T: CmpRatio,
+{
+    cmp_ratio(a_num, a_den, b_num, b_den).map(|ord| ord != Ordering::Greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmp_ratio_signs() {
+        assert_eq!(cmp_ratio(1i8, -2, -1, 3), Some(Ordering::Less));
+        assert_eq!(cmp_ratio(i8::MIN, -1, i8::MAX, 1), Some(Ordering::Greater));
+        assert_eq!(cmp_ratio(2i128, -4, -1, 2), Some(Ordering::Equal));
+        assert_eq!(cmp_ratio(i128::MIN, i128::MAX, -1, 1), Some(Ordering::Less));
+        assert_eq!(ratio_le(u128::MAX, u128::MAX, 1, 1), Some(true));
+        assert_eq!(ratio_ge(0u8, 1, 0, 0), None);
+    }
+}
@@ -0,0 +1,216 @@
+use super::{Checked, OverflowError, Unchecked};
+use std::fmt;
+
+/// Kind of the operation that overflowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    DivEuclid,
+    RemEuclid,
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OperationKind::Add => "add",
+            OperationKind::Sub => "sub",
+            OperationKind::Mul => "mul",
+            OperationKind::Div => "div",
+            OperationKind::Rem => "rem",
+            OperationKind::DivEuclid => "div_euclid",
+            OperationKind::RemEuclid => "rem_euclid",
+        })
+    }
+}
+
+/// Operand of the operation that overflowed
+///
+/// Kept as a wide primitive, so recording it doesn't allocate, and
+/// formatted only when displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Signed(v) => v.fmt(f),
+            Operand::Unsigned(v) => v.fmt(f),
+            Operand::Float(v) => v.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_for_operand {
+    ($variant:ident, $wide:ty; $($t:ty),*) => {
+        $(
+            impl From<$t> for Operand {
+                fn from(v: $t) -> Self {
+                    Operand::$variant(<$wide>::from(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_operand!(Signed, i128; i8, i16, i32, i64, i128);
+impl_from_for_operand!(Unsigned, u128; u8, u16, u32, u64, u128);
+impl_from_for_operand!(Float, f64; f32, f64);
+
+// `From` isn't implemented for pointer-sized integers, but they always fit
+impl From<isize> for Operand {
+    fn from(v: isize) -> Self {
+        Operand::Signed(v as i128)
+    }
+}
+
+impl From<usize> for Operand {
+    fn from(v: usize) -> Self {
+        Operand::Unsigned(v as u128)
+    }
+}
+
+/// Operation that caused an overflow, with its operands
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FailedOperation {
+    /// Name of the inner type, e.g. `u32`
+    pub ty: &'static str,
+    /// Kind of the operation, e.g. `add`
+    pub op: OperationKind,
+    /// Left hand side operand
+    pub lhs: Operand,
+    /// Right hand side operand
+    pub rhs: Operand,
+}
+
+impl FailedOperation {
+    #[inline(always)]
+    pub(crate) fn new<T>(ty: &'static str, op: OperationKind, lhs: T, rhs: T) -> Self
+    where
+        T: Into<Operand>,
+    {
+        FailedOperation {
+            ty,
+            op,
+            lhs: lhs.into(),
+            rhs: rhs.into(),
+        }
+    }
+}
+
+impl fmt::Display for FailedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} of {} and {}",
+            self.ty, self.op, self.lhs, self.rhs
+        )
+    }
+}
+
+/// Error returned by [`Unchecked::check_detailed()`]
+///
+/// Overflows of the arithmetic operators, `div_euclid()` and `rem_euclid()`
+/// on primitive numbers are recorded with their operands, and remembered
+/// through further operations. Others, like narrowing or custom inner types,
+/// are reported without details.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RichOverflowError {
+    operation: Option<FailedOperation>,
+}
+
+impl RichOverflowError {
+    /// The operation that overflowed, if it was recorded
+    pub fn operation(&self) -> Option<&FailedOperation> {
+        self.operation.as_ref()
+    }
+}
+
+impl fmt::Display for RichOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.operation {
+            Some(op) => write!(f, "arithmetic overflow in {}", op),
+            None => f.write_str("arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for RichOverflowError {}
+
+impl From<RichOverflowError> for OverflowError {
+    fn from(_: RichOverflowError) -> Self {
+        OverflowError
+    }
+}
+
+impl<T, D> Unchecked<T, D> {
+    /// Convert back to [`Checked`], returning an error describing the overflow
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// let err = (Checked::new(200u8) * 2 + 1).check_detailed().unwrap_err();
+    /// assert_eq!(err.to_string(), "arithmetic overflow in u8 mul of 200 and 2");
+    /// ```
+    pub fn check_detailed(self) -> Result<Checked<T, D>, RichOverflowError> {
+        match self.into_parts() {
+            (Some(v), _) => Ok(Checked::from(v)),
+            (None, overflow) => Err(RichOverflowError {
+                operation: overflow.operation,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_detailed() {
+        let err = (Checked::new(-100i32) - i32::MAX)
+            .check_detailed()
+            .unwrap_err();
+        assert_eq!(
+            err.operation(),
+            Some(&FailedOperation {
+                ty: "i32",
+                op: OperationKind::Sub,
+                lhs: Operand::Signed(-100),
+                rhs: Operand::Signed(2147483647),
+            })
+        );
+
+        // Each value keeps the details of its own overflow
+        let a = Checked::new(200u8) * 2;
+        let b = -(Checked::new(1u32) - 5u32) * 3;
+        assert!(!Checked::new(1u64).fits_add(u64::MAX));
+        assert_eq!(
+            a.check_detailed().unwrap_err().to_string(),
+            "arithmetic overflow in u8 mul of 200 and 2"
+        );
+        let b = std::thread::spawn(move || b.check_detailed()).join().unwrap();
+        assert_eq!(
+            b.unwrap_err().to_string(),
+            "arithmetic overflow in u32 sub of 1 and 5"
+        );
+
+        assert!((Checked::new(1u64) + 1).check_detailed().is_ok());
+        let err = Checked::new(300u32)
+            .narrow::<u8>()
+            .check_detailed()
+            .unwrap_err();
+        assert_eq!(err.operation(), None);
+        assert_eq!(OverflowError::from(err), OverflowError);
+
+        let err = (Checked::new(1.5f32) / 0.0).check_detailed().unwrap_err();
+        assert_eq!(err.to_string(), "arithmetic overflow in f32 div of 1.5 and 0");
+    }
+}
@@ -0,0 +1,53 @@
+use super::{ArchivedChecked, WithDeref};
+use ::rkyv::{Archive, Archived};
+use core::ops::Deref;
+
+impl<T, D> ArchivedChecked<T, D>
+where
+    T: Archive,
+{
+    /// Access the archived inner value, without deserializing
+    pub fn as_inner(&self) -> &Archived<T> {
+        &self.v
+    }
+}
+
+impl<T> Deref for ArchivedChecked<T, WithDeref>
+where
+    T: Archive,
+{
+    type Target = Archived<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Checked, WithoutDeref};
+    use ::rkyv::rancor::Error;
+
+    #[derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)]
+    struct Account {
+        balance: Checked<u64, WithoutDeref>,
+        nonce: Checked<u32>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let account = Account {
+            balance: Checked::new_without_deref(1_000),
+            nonce: Checked::new(7),
+        };
+        let bytes = ::rkyv::to_bytes::<Error>(&account).unwrap();
+
+        let archived = ::rkyv::access::<ArchivedAccount, Error>(&bytes).unwrap();
+        assert_eq!(archived.balance.as_inner().to_native(), 1_000);
+        assert_eq!(*archived.nonce, 7);
+
+        let account = ::rkyv::deserialize::<Account, Error>(archived).unwrap();
+        assert_eq!(account.balance, Checked::new_without_deref(1_000));
+        assert!({ account.nonce + u32::MAX }.check().is_none());
+    }
+}
@@ -0,0 +1,102 @@
+use super::{Checked, CheckedMulDiv, Overflow, Unchecked};
+use core::convert::TryFrom;
+
+macro_rules! impl_scale_linear {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Calculate `self * gain_num / gain_den + offset`, like when converting
+                /// a raw sensor reading to physical units
+                ///
+                /// Intermediate results are calculated in a wider type, so only
+                /// a final result that doesn't fit, or `gain_den` of zero, poisons
+                /// the result. Division truncates towards zero.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn scale_linear(
+                    self,
+                    gain_num: $t,
+                    gain_den: $t,
+                    offset: $t,
+                ) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: (self.v as $wide)
+                            .checked_mul(gain_num as $wide)
+                            .and_then(|v| v.checked_div(gain_den as $wide))
+                            .and_then(|v| v.checked_add(offset as $wide))
+                            .and_then(|v| <$t>::try_from(v).ok()),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_linear!(
+    usize => u128, isize => i128,
+    u8 => u16, i8 => i16,
+    u16 => u32, i16 => i32,
+    u32 => u64, i32 => i64,
+    u64 => u128, i64 => i128
+);
+
+macro_rules! impl_scale_linear_double_wide {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Calculate `self * gain_num / gain_den + offset`, like when converting
+                /// a raw sensor reading to physical units
+                ///
+                /// The product is calculated in double width, so only a quotient
+                /// or final result that doesn't fit, or `gain_den` of zero, poisons
+                /// the result. Division truncates towards zero.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn scale_linear(
+                    self,
+                    gain_num: $t,
+                    gain_den: $t,
+                    offset: $t,
+                ) -> Unchecked<$t, D> {
+                    Unchecked {
+                        v: self
+                            .v
+                            .checked_mul_div(gain_num, gain_den)
+                            .and_then(|v| v.checked_add(offset)),
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_linear_double_wide!(u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_linear() {
+        // 12-bit ADC reading to millivolts, with a 3300mV reference and -500mV offset
+        let raw = Checked::new(4095u16);
+        assert_eq!(*raw.scale_linear(3300, 4095, 0).check().unwrap(), 3300);
+        assert_eq!(
+            *Checked::new(4095i16)
+                .scale_linear(3300, 4095, -500)
+                .check()
+                .unwrap(),
+            2800
+        );
+        assert!(raw.scale_linear(3300, 0, 0).check().is_none());
+        assert!(raw.scale_linear(3300, 1, 0).check().is_none());
+
+        let big = Checked::new(u128::MAX);
+        assert_eq!(
+            *big.scale_linear(2, 4, 1).check().unwrap(),
+            u128::MAX / 2 + 1
+        );
+    }
+}
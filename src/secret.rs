@@ -0,0 +1,32 @@
+use super::{Checked, WithoutDeref};
+use secrecy::{CloneableSecret, SecretBox};
+use zeroize::Zeroize;
+
+/// A [`Checked`] value that must not leak through `Debug` or logs
+///
+/// The inner value is zeroized on drop and is only accessible through
+/// [`ExposeSecret::expose_secret()`](secrecy::ExposeSecret::expose_secret).
+/// Arithmetic is performed on the exposed [`Checked`] value as usual.
+///
+/// ```
+/// use overflow_proof::{Checked, SecretChecked};
+/// use secrecy::ExposeSecret;
+///
+/// let balance = SecretChecked::init_with(|| Checked::new_without_deref(100u64));
+/// assert_eq!(format!("{:?}", balance), "SecretBox<overflow_proof::Checked<u64, overflow_proof::WithoutDeref>>([REDACTED])");
+///
+/// let after = { *balance.expose_secret() - 30 }.check().expect("no overflow");
+/// assert_eq!(after.into_inner(), 70);
+/// ```
+pub type SecretChecked<T> = SecretBox<Checked<T, WithoutDeref>>;
+
+impl<T, D> Zeroize for Checked<T, D>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.v.zeroize();
+    }
+}
+
+impl<T, D> CloneableSecret for Checked<T, D> where T: Clone + Zeroize {}
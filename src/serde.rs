@@ -0,0 +1,86 @@
+use super::{Checked, Overflow, Unchecked};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::marker::PhantomData;
+
+impl<T, D> Serialize for Checked<T, D>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.v.serialize(serializer)
+    }
+}
+
+/// Like [`Checked::new()`], accepts any value of `T`, including non-finite
+/// floats; see [`Checked::new_finite()`]
+impl<'de, T, D> Deserialize<'de> for Checked<T, D>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Checked::from)
+    }
+}
+
+/// Overflow is serialized as `None`, which is `null` in most formats
+impl<T, D> Serialize for Unchecked<T, D>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.v.serialize(serializer)
+    }
+}
+
+/// Which bound was crossed is not preserved, so [`Unchecked::saturate()`]
+/// on a deserialized overflow returns `None`
+impl<'de, T, D> Deserialize<'de> for Unchecked<T, D>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        Ok(Unchecked {
+            v: Option::deserialize(deserializer)?,
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn round_trip() {
+        let v = Checked::new_without_deref(42u32);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "42");
+        assert_eq!(serde_json::from_str::<Checked<u32, WithoutDeref>>("42").unwrap(), v);
+        assert!(serde_json::from_str::<Checked<u8>>("256").is_err());
+
+        let ok = Checked::new(40u32) + 2;
+        assert_eq!(serde_json::to_string(&ok).unwrap(), "42");
+        assert_eq!(*ok.check().unwrap(), 42);
+
+        let overflow = Checked::new(u32::MAX) + 1;
+        assert_eq!(serde_json::to_string(&overflow).unwrap(), "null");
+        assert!(overflow.check().is_none());
+
+        let read: Vec<Unchecked<u8>> = serde_json::from_str("[7, null]").unwrap();
+        let read: Vec<_> = read.into_iter().map(|v| v.check().map(Checked::into_inner)).collect();
+        assert_eq!(read, [Some(7), None]);
+    }
+}
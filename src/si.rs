@@ -0,0 +1,130 @@
+use super::{Checked, Overflow, Unchecked};
+
+/// Decimal SI prefix of a unit
+///
+/// Used for conversions between units with [`Checked::scale_unit()`].
+///
+/// ```
+/// use overflow_proof::{Checked, SiPrefix};
+///
+/// let msat = Checked::new(1_500_000u64);
+/// let sat = msat.scale_unit(SiPrefix::Milli, SiPrefix::Base);
+/// assert_eq!(*sat.check().unwrap(), 1_500);
+///
+/// let sat = Checked::new(1_500u64).scale_unit(SiPrefix::Milli, SiPrefix::Base);
+/// assert!(sat.check().is_none());
+///
+/// let uw = Checked::new(5_000u32).scale_unit(SiPrefix::Base, SiPrefix::Micro);
+/// assert!(uw.check().is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SiPrefix {
+    /// 10^-12
+    Pico,
+    /// 10^-9
+    Nano,
+    /// 10^-6
+    Micro,
+    /// 10^-3
+    Milli,
+    /// 10^0, the unit itself
+    Base,
+    /// 10^3
+    Kilo,
+    /// 10^6
+    Mega,
+    /// 10^9
+    Giga,
+    /// 10^12
+    Tera,
+}
+
+impl SiPrefix {
+    /// Power of ten of the prefix
+    pub fn exponent(self) -> i32 {
+        match self {
+            SiPrefix::Pico => -12,
+            SiPrefix::Nano => -9,
+            SiPrefix::Micro => -6,
+            SiPrefix::Milli => -3,
+            SiPrefix::Base => 0,
+            SiPrefix::Kilo => 3,
+            SiPrefix::Mega => 6,
+            SiPrefix::Giga => 9,
+            SiPrefix::Tera => 12,
+        }
+    }
+}
+
+macro_rules! impl_scale_unit {
+    ($($t:ty),*) => {
+        $(
+            impl<D> Checked<$t, D> {
+                /// Convert a quantity in units with prefix `from` to units with prefix `to`
+                ///
+                /// Poisoned on overflow, or if converting to a larger unit
+                /// would lose a fractional part.
+                #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+                pub fn scale_unit(self, from: SiPrefix, to: SiPrefix) -> Unchecked<$t, D> {
+                    let exp = from.exponent() - to.exponent();
+                    let factor = (10 as $t).checked_pow(exp.unsigned_abs());
+                    Unchecked {
+                        v: match factor {
+                            Some(factor) if 0 <= exp => self.v.checked_mul(factor),
+                            Some(factor) => match self.v.checked_rem(factor) {
+                                Some(0) => self.v.checked_div(factor),
+                                _ => None,
+                            },
+                            // Factor too large to represent: only zero stays in range and exact
+                            None if self.v == 0 => Some(0),
+                            None => None,
+                        },
+                        overflow: Overflow::default(),
+                        _deref: self._deref,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_unit!(usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_unit() {
+        let mw = Checked::new(-2_000i16);
+        assert_eq!(
+            *mw.scale_unit(SiPrefix::Milli, SiPrefix::Base)
+                .check()
+                .unwrap(),
+            -2
+        );
+        assert_eq!(
+            *mw.scale_unit(SiPrefix::Mega, SiPrefix::Mega)
+                .check()
+                .unwrap(),
+            -2_000
+        );
+        assert!(mw
+            .scale_unit(SiPrefix::Base, SiPrefix::Milli)
+            .check()
+            .is_none());
+
+        let zero = Checked::new(0u8);
+        assert_eq!(
+            *zero
+                .scale_unit(SiPrefix::Pico, SiPrefix::Tera)
+                .check()
+                .unwrap(),
+            0
+        );
+        assert!(Checked::new(1u8)
+            .scale_unit(SiPrefix::Pico, SiPrefix::Tera)
+            .check()
+            .is_none());
+    }
+}
@@ -0,0 +1,52 @@
+use super::Checked;
+use ::speedy::{Context, Readable, Reader, Writable, Writer};
+
+impl<'a, C, T, D> Readable<'a, C> for Checked<T, D>
+where
+    C: Context,
+    T: Readable<'a, C>,
+{
+    fn read_from<R>(reader: &mut R) -> Result<Self, C::Error>
+    where
+        R: Reader<'a, C>,
+    {
+        T::read_from(reader).map(Checked::from)
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        T::minimum_bytes_needed()
+    }
+}
+
+impl<C, T, D> Writable<C> for Checked<T, D>
+where
+    C: Context,
+    T: Writable<C>,
+{
+    fn write_to<W>(&self, writer: &mut W) -> Result<(), C::Error>
+    where
+        W: ?Sized + Writer<C>,
+    {
+        self.v.write_to(writer)
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        self.v.bytes_needed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn round_trip() {
+        let v = Checked::new_without_deref(0x1234_5678u32);
+        let bytes = v.write_to_vec().unwrap();
+        assert_eq!(bytes, 0x1234_5678u32.write_to_vec().unwrap());
+
+        let read = Checked::<u32, WithoutDeref>::read_from_buffer(&bytes).unwrap();
+        assert_eq!(read, v);
+    }
+}
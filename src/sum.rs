@@ -0,0 +1,66 @@
+use super::Checked;
+use core::marker::PhantomData;
+
+/// Types that slices of `T` can be summed into without any possibility of overflow
+///
+/// Implemented for 128-bit accumulators and elements of at most 64 bits: a slice
+/// can't have more than `isize::MAX` elements, so the sum stays below `2^127`.
+pub trait WideSum<T>: Sized {
+    fn wide_sum(values: &[T]) -> Self;
+}
+
+macro_rules! impl_wide_sum {
+    ($wide:ty => $($t:ty),*) => {
+        $(
+            impl WideSum<$t> for $wide {
+                fn wide_sum(values: &[$t]) -> Self {
+                    values.iter().fold(0, |acc, v| acc + *v as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_sum!(u128 => usize, u8, u16, u32, u64);
+impl_wide_sum!(i128 => usize, isize, u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl<W, D> Checked<W, D> {
+    /// Sum `values` into a wider type, which can't overflow
+    ///
+    /// The result can be converted back with [`Checked::narrow()`].
+    ///
+    /// ```
+    /// use overflow_proof::Checked;
+    ///
+    /// let total = Checked::<u128>::sum_widened(&[u64::MAX, u64::MAX]);
+    /// assert_eq!(*total, 2 * u64::MAX as u128);
+    /// assert!(total.narrow::<u64>().check().is_none());
+    /// ```
+    pub fn sum_widened<T>(values: &[T]) -> Self
+    where
+        W: WideSum<T>,
+    {
+        Checked {
+            v: W::wide_sum(values),
+            _deref: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_widened() {
+        let total = Checked::<i128>::sum_widened(&[i64::MIN, i64::MIN, 1]);
+        assert_eq!(*total, 2 * i64::MIN as i128 + 1);
+        assert_eq!(
+            Checked::<i128>::sum_widened(&[1u64, 2, 3])
+                .narrow::<u8>()
+                .check(),
+            Some(Checked::new(6))
+        );
+        assert_eq!(*Checked::<u128>::sum_widened::<u32>(&[]), 0);
+    }
+}
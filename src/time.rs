@@ -0,0 +1,86 @@
+use super::{Checked, Overflow, Unchecked};
+use core::marker::PhantomData;
+use core::time::Duration;
+use std::time::{Instant, SystemTime};
+
+/// Points in time that can be moved by [`Checked`] durations
+///
+/// ```
+/// use overflow_proof::{Checked, CheckedTime};
+/// use std::time::{Duration, Instant};
+///
+/// let timeout = { Checked::new(Duration::from_millis(250)) * 4 }.check().expect("no overflow");
+/// let start = Instant::now();
+/// let deadline = start.checked_offset(timeout).expect("representable");
+///
+/// assert_eq!(deadline.checked_since(start).check(), Some(timeout));
+///
+/// let early: Option<Checked<Duration>> = start.checked_since(deadline).check();
+/// assert!(early.is_none());
+/// ```
+pub trait CheckedTime: Sized {
+    /// `self + dur`, or `None` if it's out of the range of the type
+    fn checked_offset<D>(self, dur: Checked<Duration, D>) -> Option<Self>;
+
+    /// `self - dur`, or `None` if it's out of the range of the type
+    fn checked_offset_back<D>(self, dur: Checked<Duration, D>) -> Option<Self>;
+
+    /// Time elapsed from `earlier` to `self`, overflowing if `earlier` is later
+    fn checked_since<D>(self, earlier: Self) -> Unchecked<Duration, D>;
+}
+
+impl CheckedTime for Instant {
+    fn checked_offset<D>(self, dur: Checked<Duration, D>) -> Option<Self> {
+        self.checked_add(dur.v)
+    }
+
+    fn checked_offset_back<D>(self, dur: Checked<Duration, D>) -> Option<Self> {
+        self.checked_sub(dur.v)
+    }
+
+    fn checked_since<D>(self, earlier: Self) -> Unchecked<Duration, D> {
+        Unchecked {
+            v: self.checked_duration_since(earlier),
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+impl CheckedTime for SystemTime {
+    fn checked_offset<D>(self, dur: Checked<Duration, D>) -> Option<Self> {
+        self.checked_add(dur.v)
+    }
+
+    fn checked_offset_back<D>(self, dur: Checked<Duration, D>) -> Option<Self> {
+        self.checked_sub(dur.v)
+    }
+
+    fn checked_since<D>(self, earlier: Self) -> Unchecked<Duration, D> {
+        Unchecked {
+            v: self.duration_since(earlier).ok(),
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn system_time() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let day = Checked::new_without_deref(Duration::from_secs(86_400));
+        let later = epoch.checked_offset(day).expect("representable");
+        assert_eq!(later.checked_offset_back(day), Some(epoch));
+
+        let elapsed: Unchecked<Duration, WithoutDeref> = later.checked_since(epoch);
+        assert_eq!(elapsed.check(), Some(day));
+        assert!(epoch.checked_since::<WithoutDeref>(later).check().is_none());
+
+        assert!(epoch.checked_offset(Checked::<Duration>::MAX).is_none());
+    }
+}
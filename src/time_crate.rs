@@ -0,0 +1,75 @@
+use super::{Bounded, Checked, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Zero};
+use ::time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+macro_rules! impl_checked_op_for {
+    ($t:ty, $checked_t:ident, $checked_op:ident, $rhs:ty) => {
+        impl $checked_t<$rhs> for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: $rhs) -> Option<Self::Output> {
+                <$t>::$checked_op(self, rhs)
+            }
+        }
+
+        impl<D> $checked_t<Checked<$rhs, D>> for $t {
+            type Output = $t;
+
+            fn $checked_op(self, rhs: Checked<$rhs, D>) -> Option<Self::Output> {
+                <$t>::$checked_op(self, rhs.v)
+            }
+        }
+    };
+}
+
+impl_checked_op_for!(Duration, CheckedAdd, checked_add, Duration);
+impl_checked_op_for!(Duration, CheckedSub, checked_sub, Duration);
+impl_checked_op_for!(Duration, CheckedMul, checked_mul, i32);
+impl_checked_op_for!(Duration, CheckedDiv, checked_div, i32);
+
+impl CheckedNeg for Duration {
+    type Output = Duration;
+
+    fn checked_neg(self) -> Option<Self::Output> {
+        Duration::checked_neg(self)
+    }
+}
+
+impl Bounded for Duration {
+    const MIN: Self = Duration::MIN;
+    const MAX: Self = Duration::MAX;
+}
+
+impl Zero for Duration {
+    const ZERO: Self = Duration::ZERO;
+}
+
+impl_checked_op_for!(OffsetDateTime, CheckedAdd, checked_add, Duration);
+impl_checked_op_for!(OffsetDateTime, CheckedSub, checked_sub, Duration);
+impl_checked_op_for!(PrimitiveDateTime, CheckedAdd, checked_add, Duration);
+impl_checked_op_for!(PrimitiveDateTime, CheckedSub, checked_sub, Duration);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets() {
+        let week = { Checked::new(Duration::DAY) * 7 }
+            .check()
+            .expect("no overflow");
+        let start = OffsetDateTime::UNIX_EPOCH;
+        let end = { Checked::new(start) + week - Duration::HOUR }
+            .check()
+            .expect("no overflow");
+        assert_eq!(end.unix_timestamp(), 7 * 86_400 - 3_600);
+
+        assert!({ Checked::new(start) + Checked::<Duration>::MAX }
+            .check()
+            .is_none());
+        assert!({ -Checked::<Duration>::MIN }.check().is_none());
+        assert!({ week / 0 }.check().is_none());
+        assert!({ Checked::new(PrimitiveDateTime::MAX) + Duration::SECOND }
+            .check()
+            .is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use super::{Checked, Overflow, Unchecked};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::ops::{ControlFlow, FromResidual, Try};
+
+/// `?` on [`Unchecked`] unwraps it into [`Checked`], or returns early on overflow
+///
+/// The residual is the same as of `Option`, so it works in functions returning
+/// either `Option` or `Unchecked`.
+impl<T, D> Try for Unchecked<T, D> {
+    type Output = Checked<T, D>;
+    type Residual = Option<Infallible>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Unchecked {
+            v: Some(output.v),
+            overflow: Overflow::default(),
+            _deref: output._deref,
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.check() {
+            Some(v) => ControlFlow::Continue(v),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+impl<T, D> FromResidual<Option<Infallible>> for Unchecked<T, D> {
+    fn from_residual(_: Option<Infallible>) -> Self {
+        Unchecked {
+            v: None,
+            overflow: Overflow::default(),
+            _deref: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(w: u32, h: u32, margin: u32) -> Option<u32> {
+        let w = (Checked::new(w) + 2 * margin)?;
+        let h = (Checked::new(h) + 2 * margin)?;
+        Some(*(w * *h)?)
+    }
+
+    fn volume(w: u32, h: u32, d: u32) -> Unchecked<u32> {
+        let base = (Checked::new_without_deref(w) * h)?;
+        base * d
+    }
+
+    #[test]
+    fn question_mark() {
+        assert_eq!(area(2, 3, 1), Some(20));
+        assert_eq!(area(u32::MAX, 3, 1), None);
+        assert_eq!(volume(2, 3, 4).check().map(Checked::into_inner), Some(24));
+        assert!(volume(u32::MAX, 2, 1).check().is_none());
+    }
+}
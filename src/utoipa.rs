@@ -0,0 +1,39 @@
+use super::Checked;
+use ::utoipa::openapi::{schema::Schema, RefOr};
+use ::utoipa::{PartialSchema, ToSchema};
+use std::borrow::Cow;
+
+/// Same schema as the inner type, including its integer format and bounds
+impl<T, D> PartialSchema for Checked<T, D>
+where
+    T: PartialSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        T::schema()
+    }
+}
+
+impl<T, D> ToSchema for Checked<T, D>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        T::name()
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutDeref;
+
+    #[test]
+    fn same_as_inner() {
+        assert!(Checked::<u64, WithoutDeref>::schema() == u64::schema());
+        assert_eq!(Checked::<i8>::name(), "i8");
+    }
+}
@@ -0,0 +1,29 @@
+use super::Checked;
+use ::validator::ValidateRange;
+
+/// Allows `#[validate(range(...))]` on [`Checked`] fields
+impl<T, D> ValidateRange<T> for Checked<T, D>
+where
+    T: PartialOrd,
+{
+    fn greater_than(&self, max: T) -> Option<bool> {
+        Some(self.v > max)
+    }
+
+    fn less_than(&self, min: T) -> Option<bool> {
+        Some(self.v < min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_range() {
+        let qty = Checked::new(10u32);
+        assert!(qty.validate_range(Some(1), Some(10), None, None));
+        assert!(!qty.validate_range(None, None, None, Some(10)));
+        assert!(!qty.validate_range(Some(11), None, None, None));
+    }
+}
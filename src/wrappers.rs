@@ -0,0 +1,55 @@
+use super::Checked;
+use core::marker::PhantomData;
+use core::num::{Saturating, Wrapping};
+
+macro_rules! impl_std_wrapper {
+    ($wrapper:ident, $into:ident, $behavior:literal) => {
+        impl<T, D> From<$wrapper<T>> for Checked<T, D> {
+            fn from(v: $wrapper<T>) -> Self {
+                Self {
+                    v: v.0,
+                    _deref: PhantomData,
+                }
+            }
+        }
+
+        impl<T, D> From<Checked<T, D>> for $wrapper<T> {
+            fn from(v: Checked<T, D>) -> Self {
+                $wrapper(v.v)
+            }
+        }
+
+        impl<T, D> Checked<T, D> {
+            #[doc = concat!("Convert to `", stringify!($wrapper), "`, deliberately abandoning overflow checking")]
+            ///
+            #[doc = concat!("Any further arithmetic on the result ", $behavior, " on overflow.")]
+            pub fn $into(self) -> $wrapper<T> {
+                $wrapper(self.v)
+            }
+        }
+    };
+}
+
+impl_std_wrapper!(Wrapping, into_wrapping, "silently wraps around");
+impl_std_wrapper!(
+    Saturating,
+    into_saturating,
+    "silently clamps to the bounds of `T`"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let hash = Checked::new(250u8).into_wrapping() + Wrapping(10);
+        assert_eq!(Checked::<u8>::from(hash), Checked::new(4));
+
+        let level: Saturating<u8> = Checked::new(250u8).into();
+        assert_eq!(
+            Checked::<u8>::from(level + Saturating(10)),
+            Checked::new(255)
+        );
+    }
+}